@@ -0,0 +1,48 @@
+//! Compares `StandardForm::parse_fast` with `TryFrom<&str>` on machine-written E notation.
+//!
+//! Run with `cargo bench`; it only needs `std::time`, so no benchmarking framework is required.
+
+use std::hint::black_box;
+use std::time::{Duration,Instant};
+
+use standardform::StandardForm;
+
+const ROUNDS : usize = 200;
+
+/// Returns the fastest of several timed rounds over every input, which is the least disturbed by other processes.
+fn measure(inputs : &[String],parse : impl Fn(&str) -> StandardForm) -> Duration {
+    (0..ROUNDS)
+        .map(|_| {
+            let start = Instant::now();
+            for input in inputs {
+                black_box(parse(black_box(input)));
+            }
+            start.elapsed()
+        })
+        .min()
+        .expect("there is at least one round")
+}
+
+fn main() {
+    // a fixed pseudo-random sequence, so every run parses the same inputs
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let inputs : Vec<String> = (0..10_000)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+
+            let mantissa = f64::from_bits(0x3ff0_0000_0000_0000 | (state >> 12)) * 5.0;
+            format!("{mantissa}e{}",(state % 600) as i32 - 300)
+        })
+        .collect();
+
+    let fast = measure(&inputs,|input| StandardForm::parse_fast(input).expect("input is valid E notation"));
+    let general = measure(&inputs,|input| StandardForm::try_from(input).expect("input is valid E notation"));
+
+    let per_input = |duration : Duration| duration.as_nanos() as f64 / inputs.len() as f64;
+
+    println!("parse_fast       {:>8.1} ns per number",per_input(fast));
+    println!("TryFrom<&str>    {:>8.1} ns per number",per_input(general));
+    println!("speed-up         {:>8.2}x",general.as_secs_f64() / fast.as_secs_f64());
+}
@@ -0,0 +1,241 @@
+//! Parsers for [`StandardForm`] built with [chumsky](https://docs.rs/chumsky).
+//!
+//! They accept the same grammar as their counterparts in [`crate::nom::complete`]: a decimal mantissa followed by
+//! `e5`, `E+05`, `*10^5`, `*10**5`, ` × 10^5` or `·10⁵`. An exponent that is fractional, such as the one in `1e3.7`,
+//! or doesn't fit in an `i32` is always an error, but like every chumsky parser they otherwise stop at the first
+//! character they can't consume, so add `.then_ignore(end())` to reject trailing input such as the `km` of `1e3km`.
+
+use chumsky::prelude::*;
+
+use crate::StandardForm;
+
+fn digit() -> impl Parser<char,char,Error = Simple<char>> + Clone {
+    filter(char::is_ascii_digit)
+}
+
+fn whitespace() -> impl Parser<char,(),Error = Simple<char>> + Clone {
+    one_of(" \t").repeated().ignored()
+}
+
+/// Recognises a decimal mantissa without an exponent, such as `-1.5`, `42` or `.5`.
+pub fn mantissa() -> impl Parser<char,String,Error = Simple<char>> + Clone {
+    let number = digit().repeated().at_least(1).chain::<char,_,_>(just('.').chain(digit().repeated()).or_not().flatten())
+        .or(just('.').chain(digit().repeated().at_least(1)));
+
+    one_of("+-").or_not()
+        .chain::<char,_,_>(number)
+        .collect::<String>()
+        .labelled("mantissa")
+}
+
+/// An exponent that has been recognised but may still be invalid.
+///
+/// The checks are deferred so that [`parse_standard_form_with_optional_exponent`] reports an invalid exponent instead
+/// of backtracking to the mantissa alone, which would leave e.g. the `e3.7` of `1e3.7` unconsumed.
+type Checked = Result<i32,Simple<char>>;
+
+fn checked_integer_exponent() -> impl Parser<char,Checked,Error = Simple<char>> + Clone {
+    one_of("+-").or_not()
+        .chain::<char,_,_>(digit().repeated().at_least(1))
+        .collect::<String>()
+        .then(just('.').then(digit()).rewind().or_not())
+        .map_with_span(|(exponent,fraction),span| match fraction {
+            Some(_) => Err(Simple::custom(span,"exponent must be an integer")),
+            None => exponent.parse().map_err(|_| Simple::custom(span,"exponent does not fit in an i32"))
+        })
+}
+
+fn checked_superscript_exponent() -> impl Parser<char,Checked,Error = Simple<char>> + Clone {
+    let digit = choice((
+        just('⁰').to(0),just('¹').to(1),just('²').to(2),just('³').to(3),just('⁴').to(4),
+        just('⁵').to(5),just('⁶').to(6),just('⁷').to(7),just('⁸').to(8),just('⁹').to(9),
+    ));
+
+    just('⁻').to(-1).or(just('⁺').to(1)).or_not()
+        .then(digit.repeated().at_least(1))
+        .map_with_span(|(sign,digits),span| {
+            let magnitude = digits.into_iter().fold(0_i64,|value : i64,digit : i64| value.saturating_mul(10).saturating_add(digit));
+            i32::try_from(sign.unwrap_or(1) * magnitude).map_err(|_| Simple::custom(span,"exponent does not fit in an i32"))
+        })
+}
+
+fn checked_exponent() -> impl Parser<char,Checked,Error = Simple<char>> + Clone {
+    let power = whitespace()
+        .then(one_of("*xX×·⋅"))
+        .then(whitespace())
+        .then(just("10"));
+
+    choice((
+        one_of("eE").ignore_then(checked_integer_exponent().labelled("exponent")),
+        power.clone().then(just("^").or(just("**"))).ignore_then(checked_integer_exponent().labelled("exponent")),
+        power.ignore_then(checked_superscript_exponent().labelled("exponent")),
+    ))
+}
+
+/// Parses a signed integer exponent such as `-12` or `+5`, rejecting fractional exponents and exponents that don't fit in an `i32`.
+pub fn integer_exponent() -> impl Parser<char,i32,Error = Simple<char>> + Clone {
+    checked_integer_exponent().try_map(|exponent,_| exponent).labelled("exponent")
+}
+
+/// Parses a superscript exponent such as `⁵` or `⁻¹²`.
+pub fn superscript_exponent() -> impl Parser<char,i32,Error = Simple<char>> + Clone {
+    checked_superscript_exponent().try_map(|exponent,_| exponent).labelled("exponent")
+}
+
+/// Parses any exponent: `e5`, `E-3`, `*10^5`, `x10**5`, ` × 10^-3` or `·10⁵`.
+pub fn exponent() -> impl Parser<char,i32,Error = Simple<char>> + Clone {
+    checked_exponent().try_map(|exponent,_| exponent)
+}
+
+fn build(((mantissa,exponent),span) : ((String,Checked),core::ops::Range<usize>)) -> Result<StandardForm,Simple<char>> {
+    StandardForm::from_parts(&mantissa,exponent?).ok_or_else(|| Simple::custom(span,"normalised exponent does not fit in an i32"))
+}
+
+/// Parses a string in standard form (scientific notation) and returns a `StandardForm` struct.
+///
+/// The standard form number can be written as "mantissaeexponent", "mantissaEexponent", or "mantissa*10^exponent".
+/// It supports both positive and negative exponents.
+pub fn parse_standard_form_with_required_exponent() -> impl Parser<char,StandardForm,Error = Simple<char>> + Clone {
+    mantissa().then(checked_exponent()).try_map(|parts,span| build((parts,span)))
+}
+
+/// Parses a string in standard form (scientific notation) and returns a `StandardForm` struct.
+///
+/// Like [`parse_standard_form_with_required_exponent`], but a plain mantissa such as `42` is accepted as well.
+pub fn parse_standard_form_with_optional_exponent() -> impl Parser<char,StandardForm,Error = Simple<char>> + Clone {
+    mantissa()
+        .then(checked_exponent().or_not().map(|exponent| exponent.unwrap_or(Ok(0))))
+        .try_map(|parts,span| build((parts,span)))
+}
+
+#[cfg(test)]
+mod parse_standardform_tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_standard_form_positive_exponent() {
+        let input = "1.23e3"; // Example input: 1230.0
+        match parse_standard_form_with_optional_exponent().parse(input) {
+            Ok(standard_form) => {
+                assert_eq!(standard_form.mantissa(), &1.23);
+                assert_eq!(standard_form.exponent(), &3);
+            },
+            Err(e) => panic!("Parsing error: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn test_parse_standard_form_negative_exponent() {
+        let input = "2.5*10^-2"; // Example input: 0.025
+        match parse_standard_form_with_optional_exponent().parse(input) {
+            Ok(standard_form) => {
+                assert_eq!(standard_form.mantissa(), &2.5);
+                assert_eq!(standard_form.exponent(), &-2);
+            },
+            Err(e) => panic!("Parsing error: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn test_parse_standard_form_no_exponent() {
+        let input = "42"; // No exponent, should be treated as 42.0 * 10^0
+        match parse_standard_form_with_optional_exponent().parse(input) {
+            Ok(standard_form) => {
+                assert_eq!(standard_form.mantissa(), &4.2);
+                assert_eq!(standard_form.exponent(), &1);
+            },
+            Err(e) => panic!("Parsing error: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn test_parse_standard_form_star_exponent() {
+        let input = "5*10^4"; // Example input: 50000.0
+        match parse_standard_form_with_optional_exponent().parse(input) {
+            Ok(standard_form) => {
+                assert_eq!(standard_form.mantissa(), &5.0);
+                assert_eq!(standard_form.exponent(), &4);
+            },
+            Err(e) => panic!("Parsing error: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn test_parse_standard_form_invalid_input() {
+        let input = "invalid"; // Invalid input, should result in an error
+        assert!(parse_standard_form_with_optional_exponent().parse(input).is_err());
+    }
+
+    #[test]
+    fn test_parse_standard_form_required_exponent() {
+        assert!(parse_standard_form_with_required_exponent().then_ignore(end()).parse("1.23e3").is_ok());
+        assert!(parse_standard_form_with_required_exponent().parse("42").is_err());
+    }
+
+    #[test]
+    fn test_parse_standard_form_unicode() {
+        let parser = parse_standard_form_with_required_exponent().then_ignore(end());
+        assert_eq!(parser.parse("1.2 × 10⁻⁵"), Ok(StandardForm::new(1.2, -5)));
+    }
+
+    #[test]
+    fn test_parse_standard_form_error_spans() {
+        let parser = parse_standard_form_with_optional_exponent().then_ignore(end());
+
+        for (input, span, message) in [
+            ("1e3.7", 2..3, "exponent must be an integer"),
+            ("1e3000000000", 2..12, "exponent does not fit in an i32"),
+            ("1·10⁹⁹⁹⁹⁹⁹⁹⁹⁹⁹", 4..14, "exponent does not fit in an i32"),
+            ("500e2147483646", 0..14, "normalised exponent does not fit in an i32"),
+        ] {
+            let errors = parser.parse(input).unwrap_err();
+            assert_eq!(errors.len(), 1, "{input}");
+            assert_eq!(errors[0].span(), span, "{input}");
+            assert_eq!(errors[0].reason(), &chumsky::error::SimpleReason::Custom(message.to_string()), "{input}");
+        }
+
+        let errors = parser.parse("abc").unwrap_err();
+        assert_eq!(errors[0].span(), 0..1);
+        assert_eq!(errors[0].label(), Some("mantissa"));
+        assert_eq!(errors[0].found(), Some(&'a'));
+    }
+
+    #[test]
+    fn test_parse_standard_form_recovery() {
+        let list = parse_standard_form_with_required_exponent()
+            .recover_with(skip_until([','], |_| StandardForm::new(f64::NAN, 0)))
+            .separated_by(just(','))
+            .then_ignore(end());
+
+        let (numbers, errors) = list.parse_recovery("1e3,oops,2.5e-1,4e3.5,7e0");
+
+        assert_eq!(numbers, Some(vec![
+            StandardForm::new(1.0, 3),
+            StandardForm::new(f64::NAN, 0),
+            StandardForm::new(2.5, -1),
+            StandardForm::new(f64::NAN, 0),
+            StandardForm::new(7.0, 0),
+        ]));
+        assert_eq!(errors.iter().map(Simple::span).collect::<Vec<_>>(), [4..5, 18..19]);
+        assert_eq!(errors[1].reason(), &chumsky::error::SimpleReason::Custom("exponent must be an integer".to_string()));
+    }
+
+    #[test]
+    fn test_parse_standard_form_strict_exponent() {
+        let parser = parse_standard_form_with_optional_exponent().then_ignore(end());
+        assert!(parser.parse("1*10^3.7").is_err());
+        assert!(parser.parse("1e3.7").is_err());
+        assert!(parser.parse("1e3000000000").is_err());
+        assert!(parser.parse("500e2147483646").is_err());
+        assert!(parser.parse("9.99999999999999999999e2147483647").is_err());
+        assert_eq!(parser.parse("9.99999999999999999999e2147483646"), Ok(StandardForm::new(1.0, 2147483647)));
+        assert!(parse_standard_form_with_optional_exponent().then_ignore(just('.')).parse("1e3.").is_ok());
+
+        // an invalid exponent is rejected even without `end()`, unlike other trailing input
+        assert!(parse_standard_form_with_optional_exponent().parse("1e3.7").is_err());
+        assert!(parse_standard_form_with_optional_exponent().parse("1e3000000000").is_err());
+        assert!(parse_standard_form_with_optional_exponent().parse("1·10⁹⁹⁹⁹⁹⁹⁹⁹⁹⁹").is_err());
+        assert_eq!(parse_standard_form_with_optional_exponent().parse("1e3km"), Ok(StandardForm::new(1.0, 3)));
+        assert!(parser.parse("1e3km").is_err());
+    }
+}
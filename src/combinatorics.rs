@@ -0,0 +1,274 @@
+use core::f64::consts::{LN_10,PI,TAU};
+
+use crate::StandardForm;
+use crate::sf::decompose;
+use crate::math::power_of_ten;
+
+/// Products of up to this many terms are evaluated term by term, larger ones through [`ln_gamma`].
+const MAX_PRODUCT_TERMS : u64 = 170;
+
+/// Multiplies the terms exactly, returning `None` if the product doesn't fit in a `u128`.
+fn exact_product(terms : impl Iterator<Item = u64>) -> Option<u128> {
+    terms.into_iter().try_fold(1_u128,|product,term| product.checked_mul(u128::from(term)))
+}
+
+/// Multiplies the terms in `f64`, moving powers of ten into a separate exponent so the product can't overflow.
+fn product(terms : impl Iterator<Item = f64>) -> StandardForm {
+    let (mut mantissa,mut exponent) = (1.0,0_i64);
+
+    for term in terms {
+        let (term,shift) = decompose(term);
+        let (product,carry) = decompose(mantissa * term);
+
+        mantissa = product;
+        exponent += i64::from(shift) + i64::from(carry);
+    }
+
+    StandardForm::saturating_new(mantissa,exponent)
+}
+
+/// Returns `ln|Γ(x)|` for an `f64`, which is infinite at the poles `0, -1, -2, …` and at both infinities.
+fn ln_gamma(x : f64) -> f64 {
+    if (x <= 0.0 && x.fract() == 0.0) || x.is_infinite() {
+        return f64::INFINITY;
+    }
+
+    if x == 1.0 || x == 2.0 {
+        return 0.0;
+    }
+
+    // reflection formula, Γ(x)Γ(1 - x) = π / sin(πx)
+    if x < 0.5 {
+        return (PI / (PI * x).sin().abs()).ln() - ln_gamma(1.0 - x);
+    }
+
+    // Γ(x + 1) = xΓ(x) moves small arguments to where Stirling's series is accurate
+    let mut x = x;
+    let mut shifted = 1.0;
+
+    while x < 10.0 {
+        shifted *= x;
+        x += 1.0;
+    }
+
+    (x - 0.5) * x.ln() - x + 0.5 * TAU.ln() + stirling_series(x) - shifted.ln()
+}
+
+/// Returns the correction terms of Stirling's series for `ln Γ(x)`, whose next term is below 1e-15 from `x = 10`.
+fn stirling_series(x : f64) -> f64 {
+    const COEFFICIENTS : [f64;7] = [1.0 / 12.0,-1.0 / 360.0,1.0 / 1260.0,-1.0 / 1680.0,1.0 / 1188.0,-691.0 / 360_360.0,1.0 / 156.0];
+
+    let square = x * x;
+    COEFFICIENTS.iter().rev().fold(0.0,|sum,coefficient| sum / square + coefficient) / x
+}
+
+/// Returns `ln(n! / (n - k)!)`.
+///
+/// Subtracting the two log-gammas would cancel almost all digits when `k` is small next to `n`, so their Stirling
+/// series are subtracted term by term instead, leaving `ln(n + 1) - ln(n - k + 1)` to `ln_1p`.
+fn ln_falling_factorial(n : u64,k : u64) -> f64 {
+    let (x,y) = (n as f64 + 1.0,(n - k) as f64 + 1.0);
+
+    if y < 10.0 {
+        return ln_gamma(x) - ln_gamma(y);
+    }
+
+    let k = k as f64;
+    -(y - 0.5) * (-k / x).ln_1p() + k * x.ln() - k + stirling_series(x) - stirling_series(y)
+}
+
+/// Combinatorial and special functions, evaluated in log space when the result is too large for an `f64`.
+///
+/// A result evaluated in log space keeps about `16 - log10(log10(result))` significant digits, so `1000!` is accurate
+/// to about 12 digits and `1000000!` to about 9.
+impl StandardForm {
+    /// Returns `n!`, which is exact while it fits in a `u128`.
+    ///
+    /// ```
+    /// use standardform::StandardForm;
+    ///
+    /// assert_eq!(StandardForm::factorial(20), StandardForm::from(2_432_902_008_176_640_000_u64));
+    /// assert_eq!(*StandardForm::factorial(1000).exponent(), 2567);
+    /// ```
+    #[must_use]
+    pub fn factorial(n : u64) -> Self {
+        Self::permutations(n,n)
+    }
+
+    /// Returns the number of ways to arrange `k` of `n` items in order, `n! / (n - k)!`, which is zero if `k > n`.
+    #[must_use]
+    pub fn permutations(n : u64,k : u64) -> Self {
+        if k > n {
+            return Self::new(0.0,0);
+        }
+
+        let terms = n - k + 1..=n;
+
+        if let Some(exact) = exact_product(terms.clone()) {
+            return exact.into();
+        }
+
+        match k <= MAX_PRODUCT_TERMS {
+            true => product(terms.map(|term| term as f64)),
+            false => power_of_ten(ln_falling_factorial(n,k) / LN_10)
+        }
+    }
+
+    /// Returns the number of ways to choose `k` of `n` items, `n! / (k! (n - k)!)`, which is zero if `k > n`.
+    ///
+    /// ```
+    /// use standardform::StandardForm;
+    ///
+    /// assert_eq!(StandardForm::binomial(52,5), StandardForm::from(2_598_960));
+    /// ```
+    #[must_use]
+    pub fn binomial(n : u64,k : u64) -> Self {
+        if k > n {
+            return Self::new(0.0,0);
+        }
+
+        let k = k.min(n - k);
+
+        // every prefix of the product is itself a binomial coefficient, so each division is exact
+        let exact = (1..=k).try_fold(1_u128,|product,i| product.checked_mul(u128::from(n - k + i)).map(|product| product / u128::from(i)));
+
+        if let Some(exact) = exact {
+            return exact.into();
+        }
+
+        match k <= MAX_PRODUCT_TERMS {
+            true => product((1..=k).map(|i| (n - k + i) as f64 / i as f64)),
+            false => power_of_ten((ln_falling_factorial(n,k) - ln_gamma(k as f64 + 1.0)) / LN_10)
+        }
+    }
+
+    /// Returns the gamma function `Γ(self)`, which is `(n - 1)!` for a positive integer `n`. It is NaN at the poles
+    /// `-1, -2, …` and infinite at zero.
+    ///
+    /// ```
+    /// use standardform::StandardForm;
+    ///
+    /// assert_eq!(StandardForm::new(5.0,0).gamma(), StandardForm::new(2.4,1));
+    /// ```
+    #[must_use]
+    pub fn gamma(self) -> Self {
+        let x = f64::from(self);
+
+        if x.is_nan() || x == f64::NEG_INFINITY || (x < 0.0 && x.fract() == 0.0) {
+            return Self::new(f64::NAN,0);
+        }
+
+        if x == 0.0 || x == f64::INFINITY {
+            return Self::new(f64::INFINITY,0);
+        }
+
+        if x.fract() == 0.0 && x <= (MAX_PRODUCT_TERMS + 1) as f64 {
+            return Self::factorial(x as u64 - 1);
+        }
+
+        // Γ(x) is negative between an odd negative integer and the integer above it
+        let magnitude = power_of_ten(ln_gamma(x) / LN_10);
+
+        match x < 0.0 && (x.floor() as i64) % 2 != 0 {
+            true => -magnitude,
+            false => magnitude
+        }
+    }
+
+    /// Returns `ln|Γ(self)|`, which is infinite at the poles `0, -1, -2, …` and defined beyond the range of `f64`.
+    #[must_use]
+    pub fn ln_gamma(self) -> Self {
+        // every f64 from 10^16 on is an integer, so below that the f64 evaluation still sees the fractional part
+        if *self.exponent() < 16 || !self.mantissa().is_finite() {
+            return ln_gamma(f64::from(self)).into();
+        }
+
+        match *self.mantissa() > 0.0 {
+            // x ln x - x, as the other terms of Stirling's series are below the precision of an f64 here
+            true => {
+                let ln = f64::from(*self.exponent()) * LN_10 + self.mantissa().ln();
+                Self::saturating_new(self.mantissa() * (ln - 1.0),i64::from(*self.exponent()))
+            },
+            // negative numbers this large are all poles
+            false => Self::new(f64::INFINITY,0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Asserts that the relative difference between the numbers is below the tolerance.
+    fn assert_close(actual : StandardForm,expected : StandardForm,tolerance : f64) {
+        let ratio = f64::from(actual / expected);
+        assert!((ratio - 1.0).abs() < tolerance, "{actual} is not close to {expected}");
+    }
+
+    #[test]
+    fn factorials() {
+        assert_eq!(StandardForm::factorial(0), StandardForm::new(1.0,0));
+        assert_eq!(StandardForm::factorial(5), StandardForm::new(1.2,2));
+        assert_eq!(StandardForm::factorial(34), StandardForm::from(295_232_799_039_604_140_847_618_609_643_520_000_000_u128));
+
+        assert_close(StandardForm::factorial(100), StandardForm::new(9.332_621_544_394_415,157),1e-12);
+        assert_close(StandardForm::factorial(1000), StandardForm::new(4.023_872_600_770_938,2567),1e-11);
+        assert_close(StandardForm::factorial(1_000_000), StandardForm::new(8.263_931_688_331_24,5_565_708),1e-8);
+    }
+
+    #[test]
+    fn permutations_and_binomials() {
+        assert_eq!(StandardForm::permutations(10,3), StandardForm::new(7.2,2));
+        assert_eq!(StandardForm::permutations(3,4), StandardForm::new(0.0,0));
+        assert_eq!(StandardForm::permutations(u64::MAX,1), StandardForm::from(u64::MAX));
+        assert_close(StandardForm::permutations(1000,500), StandardForm::factorial(1000) / StandardForm::factorial(500),1e-12);
+
+        assert_eq!(StandardForm::binomial(52,5), StandardForm::new(2.59896,6));
+        assert_eq!(StandardForm::binomial(10,0), StandardForm::new(1.0,0));
+        assert_eq!(StandardForm::binomial(10,10), StandardForm::new(1.0,0));
+        assert_eq!(StandardForm::binomial(3,4), StandardForm::new(0.0,0));
+        assert_eq!(StandardForm::binomial(100,50), StandardForm::from(100_891_344_545_564_193_334_812_497_256_u128));
+
+        assert_close(StandardForm::binomial(1000,500), StandardForm::new(2.702_882_409_454_365,299),1e-12);
+        assert_close(StandardForm::binomial(100_000,50_000), StandardForm::new(2.520_608_368_922_003,30100),1e-9);
+        assert_close(StandardForm::binomial(1_000_000_000_000,3), StandardForm::new(1.666_666_666_661_666_7,35),1e-12);
+
+        // a few hundred terms from a huge n, where subtracting two log-gammas of about 3e16 would cancel
+        assert_close(StandardForm::permutations(1_000_000_000_000_000,200), StandardForm::new(9.999_999_999_801,2999),1e-11);
+        assert_close(StandardForm::binomial(1_000_000_000_000_000,200), StandardForm::new(1.267_976_953_455_729_6,2625),1e-11);
+        assert_close(StandardForm::permutations(1_000_000_000_000,5000), StandardForm::new(9.999_875_025_780_726,59999),1e-10);
+        assert_close(StandardForm::binomial(1_000_000_000_000,5000), StandardForm::new(2.364_831_676_120_497_6,43674),1e-10);
+    }
+
+    #[test]
+    fn gamma() {
+        assert_eq!(StandardForm::new(1.0,0).gamma(), StandardForm::new(1.0,0));
+        assert_eq!(StandardForm::new(1.0,1).gamma(), StandardForm::from(362_880));
+        assert_close(StandardForm::new(5.0,-1).gamma(), StandardForm::from(PI.sqrt()),1e-12);
+        assert_close(StandardForm::new(-5.0,-1).gamma(), StandardForm::from(-2.0 * PI.sqrt()),1e-12);
+        assert_close(StandardForm::new(-1.5,0).gamma(), StandardForm::from(4.0 / 3.0 * PI.sqrt()),1e-12);
+        assert_close(StandardForm::new(1.0,3).gamma(), StandardForm::factorial(999),1e-12);
+        assert_close(StandardForm::new(1.0015,3).gamma() / StandardForm::new(1.0005,3).gamma(), StandardForm::new(1.0005,3),1e-12);
+
+        assert!(StandardForm::new(-2.0,0).gamma().mantissa().is_nan());
+        assert_eq!(StandardForm::new(0.0,0).gamma(), StandardForm::new(f64::INFINITY,0));
+        assert_eq!(StandardForm::new(1.0,10).gamma(), StandardForm::new(f64::INFINITY,0));
+    }
+
+    #[test]
+    fn log_gamma() {
+        assert_eq!(StandardForm::new(1.0,0).ln_gamma(), StandardForm::new(0.0,0));
+        assert_close(StandardForm::new(5.0,-1).ln_gamma(), StandardForm::from(PI.sqrt().ln()),1e-12);
+        assert_close(StandardForm::new(-5.0,-1).ln_gamma(), StandardForm::from((2.0 * PI.sqrt()).ln()),1e-12);
+        assert_close(StandardForm::new(1.0,2).ln_gamma(), StandardForm::from(359.134_205_369_575_4),1e-12);
+        assert_eq!(StandardForm::new(-3.0,0).ln_gamma(), StandardForm::new(f64::INFINITY,0));
+        assert_eq!(StandardForm::new(f64::INFINITY,0).ln_gamma(), StandardForm::new(f64::INFINITY,0));
+
+        // negative numbers below 10^16 can still have a fractional part, so they aren't poles
+        assert!(StandardForm::new(-1.234_567_890_123_456_7,15).ln_gamma().mantissa().is_finite());
+
+        // far beyond the range of f64, ln Γ(x) is x ln x - x
+        let huge = StandardForm::new(1.0,400).ln_gamma();
+        assert_close(huge, StandardForm::new(400.0 * LN_10 - 1.0,400),1e-12);
+    }
+}
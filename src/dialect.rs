@@ -0,0 +1,367 @@
+use crate::{StandardForm,ParseOptions,ParsingStandardFormError,ParsingStandardFormDiagnostic};
+use crate::parse::{Cursor,Mantissa,mantissa,signed_digits,normalise};
+
+/// Notations written and read by other languages and tools, used by [`StandardForm::parse_with_dialect`]
+/// and [`StandardForm::to_dialect_string`].
+#[derive(Debug,Clone,Copy,PartialEq,Eq,Hash)]
+pub enum Dialect {
+    /// Fortran's double precision notation, e.g. `1.0D+03`.
+    ///
+    /// `E` and `Q` are accepted as exponent letters as well, and the letter may be omitted before a signed exponent, e.g. `1.0+100`.
+    Fortran,
+    /// Mathematica's notation, e.g. `1.2*^5`.
+    ///
+    /// A precision or accuracy mark is accepted and ignored, e.g. `` 1.2`*^5 `` or `` 1.2`20.*^5 ``.
+    Mathematica,
+    /// Maple's software float constructor, e.g. `Float(12, 4)` for `12 * 10^4`.
+    ///
+    /// Plain decimal notation such as `1.2e5` is accepted as well.
+    Maple,
+    /// C99 hexadecimal floating point, e.g. `0x1.8p3` for `1.5 * 2^3`, as read by `strtod` and written by `printf("%a")`.
+    ///
+    /// Like `strtod` and `printf("%a")`, this goes through `f64`, so it is lossy outside of its range: larger numbers
+    /// are written as `inf`, smaller ones as a zero or a subnormal with fewer significant bits.
+    CHexFloat
+}
+
+impl StandardForm {
+    /// Parses `input` written in the notation of `dialect`.
+    pub fn parse_with_dialect(input : &str,dialect : Dialect) -> Result<Self,ParsingStandardFormDiagnostic> {
+        let options = ParseOptions::strict().leading_plus(true).whitespace(true);
+        let mut cursor = Cursor::new(input,&options);
+
+        if cursor.peek().is_none() {
+            return Err(cursor.error(ParsingStandardFormError::Empty,0..input.len(),"a number"));
+        }
+
+        let start = cursor.position;
+
+        let (mantissa,exponent) = match dialect {
+            Dialect::Fortran => fortran(&mut cursor)?,
+            Dialect::Mathematica => mathematica(&mut cursor)?,
+            Dialect::Maple => match cursor.rest().starts_with("Float(") {
+                true => maple(&mut cursor)?,
+                false => return crate::parse::parse(input,&ParseOptions::default())
+            },
+            Dialect::CHexFloat => (hex_float(&mut cursor)?,0)
+        };
+
+        if cursor.peek().is_some() {
+            return Err(cursor.unexpected("end of input"));
+        }
+
+        match mantissa.is_finite() {
+            true => normalise(mantissa,exponent).map_err(|error| cursor.error(error,start..cursor.end,"a number within the representable range")),
+            false => Ok(StandardForm::new(mantissa,0))
+        }
+    }
+
+    /// Returns the number written in the notation of `dialect`, so that it can be read back by the corresponding tool.
+    ///
+    /// This is exact for every dialect but [`Dialect::CHexFloat`], which can only write numbers within the range of `f64`.
+    #[must_use]
+    pub fn to_dialect_string(&self,dialect : Dialect) -> String {
+        if !self.mantissa().is_finite() && dialect != Dialect::CHexFloat {
+            return self.to_string();
+        }
+
+        match dialect {
+            Dialect::Fortran => format!("{:?}D{:+03}",self.mantissa(),self.exponent()),
+            Dialect::Mathematica => match self.exponent() {
+                0 => format!("{}",self.mantissa()),
+                exponent => format!("{}*^{exponent}",self.mantissa())
+            },
+            Dialect::Maple => {
+                // shortest round-trip digits, e.g. "1.2e0" for 1.2
+                let scientific = format!("{:e}",self.mantissa());
+                let (significand,exponent) = scientific.split_once('e').expect("`{:e}` always contains an exponent");
+
+                // in i64, as removing the fraction digits can take the exponent below i32::MIN
+                let fraction_digits = significand.split_once('.').map_or(0,|(_,fraction)| fraction.len()) as i64;
+                let exponent = exponent.parse::<i64>().expect("`{:e}` always has an integer exponent") + i64::from(*self.exponent()) - fraction_digits;
+
+                format!("Float({}, {exponent})",significand.replace('.',""))
+            },
+            Dialect::CHexFloat => to_hex_float(f64::from(*self))
+        }
+    }
+}
+
+fn finite_mantissa(cursor : &mut Cursor) -> Result<f64,ParsingStandardFormDiagnostic> {
+    let start = cursor.position;
+
+    match mantissa(cursor)? {
+        Mantissa::Finite(mantissa) => Ok(mantissa),
+        Mantissa::Special(_) => {
+            cursor.position = start;
+            Err(cursor.unexpected("a digit"))
+        }
+    }
+}
+
+fn fortran(cursor : &mut Cursor) -> Result<(f64,i32),ParsingStandardFormDiagnostic> {
+    let mantissa = finite_mantissa(cursor)?;
+
+    let exponent = match cursor.eat(|character| "DdEeQq".contains(character)) {
+        Some(_) => signed_digits(cursor)?,
+        None if cursor.peek().is_some_and(|character| character == '+' || character == '-') => signed_digits(cursor)?,
+        None => 0
+    };
+
+    Ok((mantissa,exponent))
+}
+
+fn mathematica(cursor : &mut Cursor) -> Result<(f64,i32),ParsingStandardFormDiagnostic> {
+    let mantissa = finite_mantissa(cursor)?;
+
+    if cursor.eat(|character| character == '`').is_some() {
+        let _ = cursor.eat(|character| character == '`');
+        let _ = cursor.eat_while(|character| character.is_ascii_digit() || character == '.');
+    }
+
+    let exponent = match cursor.eat_str("*^") {
+        true => signed_digits(cursor)?,
+        false => 0
+    };
+
+    Ok((mantissa,exponent))
+}
+
+fn maple(cursor : &mut Cursor) -> Result<(f64,i32),ParsingStandardFormDiagnostic> {
+    let _ = cursor.eat_str("Float(");
+    let _ = cursor.eat_while(char::is_whitespace);
+
+    let start = cursor.position;
+    let _ = cursor.eat(|character| character == '+' || character == '-');
+
+    if cursor.eat_while(|character| character.is_ascii_digit()).is_empty() {
+        return Err(cursor.unexpected("an integer mantissa"));
+    }
+
+    let span = start..cursor.position;
+    let mantissa : f64 = cursor.input[span.clone()].parse().map_err(|error| cursor.error(ParsingStandardFormError::Mantissa(error),span,"an integer mantissa"))?;
+
+    let _ = cursor.eat_while(char::is_whitespace);
+    if cursor.eat(|character| character == ',').is_none() {
+        return Err(cursor.unexpected("','"));
+    }
+    let _ = cursor.eat_while(char::is_whitespace);
+
+    let exponent = signed_digits(cursor)?;
+
+    let _ = cursor.eat_while(char::is_whitespace);
+    if cursor.eat(|character| character == ')').is_none() {
+        return Err(cursor.unexpected("')'"));
+    }
+
+    Ok((mantissa,exponent))
+}
+
+fn hex_float(cursor : &mut Cursor) -> Result<f64,ParsingStandardFormDiagnostic> {
+    let negative = cursor.eat(|character| character == '+' || character == '-') == Some('-');
+
+    if !cursor.eat_str("0x") && !cursor.eat_str("0X") {
+        return Err(cursor.unexpected("'0x'"));
+    }
+
+    // Only the first 15 hex digits (60 bits) are kept, which is more than the 53 bits an f64 can hold. Whether any of
+    // the dropped digits is non-zero is kept as a sticky bit, so that the conversion to f64 still rounds correctly
+    let mut significand : u64 = 0;
+    let mut sticky = false;
+    let mut digits = 0;
+    let mut binary_exponent : i32 = 0;
+
+    let mut any_digit = false;
+    let mut fraction = false;
+
+    loop {
+        match cursor.peek() {
+            Some('.') if !fraction => fraction = true,
+            Some(character) if character.is_ascii_hexdigit() => {
+                let value = character.to_digit(16).expect("character is a hex digit") as u64;
+                any_digit = true;
+
+                match (digits < 15,fraction) {
+                    (true,_) => {
+                        significand = significand << 4 | value;
+                        if significand != 0 {
+                            digits += 1;
+                        }
+                        if fraction {
+                            binary_exponent -= 4;
+                        }
+                    },
+                    (false,false) => {
+                        binary_exponent += 4;
+                        sticky |= value != 0;
+                    },
+                    (false,true) => sticky |= value != 0
+                }
+            },
+            _ => break
+        }
+
+        let _ = cursor.bump();
+    }
+
+    if !any_digit {
+        return Err(cursor.unexpected("a hexadecimal digit"));
+    }
+
+    if cursor.eat(|character| character == 'p' || character == 'P').is_some() {
+        let start = cursor.position;
+        let _ = cursor.eat(|character| character == '+' || character == '-');
+
+        if cursor.eat_while(|character| character.is_ascii_digit()).is_empty() {
+            return Err(cursor.unexpected("a binary exponent"));
+        }
+
+        let span = start..cursor.position;
+        let exponent : i32 = cursor.input[span.clone()].parse().map_err(|error| cursor.error(ParsingStandardFormError::Exponent(error),span,"a binary exponent"))?;
+        binary_exponent = binary_exponent.saturating_add(exponent);
+    }
+
+    if sticky {
+        significand |= 1;
+    }
+
+    let value = scale_by_power_of_two(significand as f64,binary_exponent);
+
+    Ok(match negative {
+        true => -value,
+        false => value
+    })
+}
+
+/// Computes `value * 2^exponent` in steps, so intermediate powers of two don't overflow or underflow.
+fn scale_by_power_of_two(mut value : f64,mut exponent : i32) -> f64 {
+    while exponent > 1000 {
+        value *= 2_f64.powi(1000);
+        exponent -= 1000;
+    }
+
+    while exponent < -1000 {
+        value *= 2_f64.powi(-1000);
+        exponent += 1000;
+    }
+
+    value * 2_f64.powi(exponent)
+}
+
+fn to_hex_float(value : f64) -> String {
+    if value.is_nan() {
+        return "nan".to_string();
+    }
+
+    let sign = match value.is_sign_negative() {
+        true => "-",
+        false => ""
+    };
+
+    if value.is_infinite() {
+        return format!("{sign}inf");
+    }
+
+    if value == 0.0 {
+        return format!("{sign}0x0p+0");
+    }
+
+    let bits = value.to_bits();
+    let biased_exponent = ((bits >> 52) & 0x7ff) as i32;
+    let fraction = bits & ((1 << 52) - 1);
+
+    let (leading,exponent) = match biased_exponent {
+        0 => (0,-1022),
+        _ => (1,biased_exponent - 1023)
+    };
+
+    let fraction = format!("{fraction:013x}");
+    let fraction = fraction.trim_end_matches('0');
+
+    match fraction.is_empty() {
+        true => format!("{sign}0x{leading}p{exponent:+}"),
+        false => format!("{sign}0x{leading}.{fraction}p{exponent:+}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_parses(input : &str,dialect : Dialect,mantissa : f64,exponent : i32) {
+        match StandardForm::parse_with_dialect(input,dialect) {
+            Ok(standard_form) => {
+                assert_eq!(standard_form.mantissa(), &mantissa, "mantissa of {input:?}");
+                assert_eq!(standard_form.exponent(), &exponent, "exponent of {input:?}");
+            },
+            Err(e) => panic!("Parsing error for {input:?}: {e}"),
+        }
+    }
+
+    #[test]
+    fn fortran() {
+        assert_parses("1.0D+03", Dialect::Fortran, 1.0, 3);
+        assert_parses("-2.5d-2", Dialect::Fortran, -2.5, -2);
+        assert_parses("1.0+100", Dialect::Fortran, 1.0, 100);
+        assert_eq!(StandardForm::new(1.0, 3).to_dialect_string(Dialect::Fortran), "1.0D+03");
+        assert_eq!(StandardForm::new(-2.5, -12).to_dialect_string(Dialect::Fortran), "-2.5D-12");
+    }
+
+    #[test]
+    fn mathematica() {
+        assert_parses("1.2*^5", Dialect::Mathematica, 1.2, 5);
+        assert_parses("1.2`*^5", Dialect::Mathematica, 1.2, 5);
+        assert_parses("1.2`20.*^-5", Dialect::Mathematica, 1.2, -5);
+        assert_eq!(StandardForm::new(1.2, 5).to_dialect_string(Dialect::Mathematica), "1.2*^5");
+    }
+
+    #[test]
+    fn maple() {
+        assert_parses("Float(12, 4)", Dialect::Maple, 1.2, 5);
+        assert_parses("Float(-15,-3)", Dialect::Maple, -1.5, -2);
+        assert_parses("1.2e5", Dialect::Maple, 1.2, 5);
+        assert_eq!(StandardForm::new(1.2, 5).to_dialect_string(Dialect::Maple), "Float(12, 4)");
+        assert_eq!(StandardForm::new(-3.0, -2).to_dialect_string(Dialect::Maple), "Float(-3, -2)");
+    }
+
+    #[test]
+    fn hex_float() {
+        assert_parses("0x1.8p3", Dialect::CHexFloat, 1.2, 1);
+        assert_parses("-0X.8P1", Dialect::CHexFloat, -1.0, 0);
+        assert_parses("0x1p-4", Dialect::CHexFloat, 6.25, -2);
+        assert_eq!(StandardForm::new(1.2, 1).to_dialect_string(Dialect::CHexFloat), "0x1.8p+3");
+        assert_eq!(StandardForm::new(0.0, 0).to_dialect_string(Dialect::CHexFloat), "0x0p+0");
+
+        // exactly halfway between 1 and the next f64 rounds to even, but any further non-zero digit rounds up
+        assert_parses("0x1.00000000000008p0", Dialect::CHexFloat, 1.0, 0);
+        assert_parses("0x1.000000000000080000p0", Dialect::CHexFloat, 1.0, 0);
+        assert_parses("0x1.000000000000080001p0", Dialect::CHexFloat, 1.0000000000000002, 0);
+        assert_parses("0x100000000000008001p-68", Dialect::CHexFloat, 1.0000000000000002, 0);
+
+        // numbers beyond the range of f64 can't be written exactly
+        assert_eq!(StandardForm::new(1.0, 400).to_dialect_string(Dialect::CHexFloat), "inf");
+        assert_eq!(StandardForm::new(-1.0, -400).to_dialect_string(Dialect::CHexFloat), "-0x0p+0");
+    }
+
+    #[test]
+    fn round_trip() {
+        let number = StandardForm::new(6.02214076, 23);
+
+        for dialect in [Dialect::Fortran, Dialect::Mathematica, Dialect::Maple] {
+            let written = number.to_dialect_string(dialect);
+            let read = StandardForm::parse_with_dialect(&written, dialect).unwrap();
+            assert_eq!(read, number, "{dialect:?} wrote {written}");
+        }
+
+        let written = number.to_dialect_string(Dialect::CHexFloat);
+        assert_eq!(StandardForm::parse_with_dialect(&written, Dialect::CHexFloat).unwrap(), number, "CHexFloat wrote {written}");
+    }
+
+    #[test]
+    fn errors() {
+        assert!(StandardForm::parse_with_dialect("1.0D", Dialect::Fortran).is_err());
+        assert!(StandardForm::parse_with_dialect("1.2*^", Dialect::Mathematica).is_err());
+        assert!(StandardForm::parse_with_dialect("Float(1.2, 4)", Dialect::Maple).is_err());
+        assert!(StandardForm::parse_with_dialect("1.8p3", Dialect::CHexFloat).is_err());
+    }
+}
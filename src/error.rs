@@ -1,5 +1,9 @@
 use std::num::{ParseIntError,ParseFloatError};
 use std::str::Utf8Error;
+use std::ops::Range;
+use std::fmt;
+use std::io;
+use std::sync::Arc;
 
 use thiserror::Error;
 
@@ -15,6 +19,37 @@ pub enum ParsingStandardFormError {
     /// Indicates an invalid format that doesn't match any valid `StandardForm` notation.
     #[error("Invalid format")]
     InvalidFormat,
+    /// Occurs when the input is empty or only contains whitespace.
+    #[error("Input is empty")]
+    Empty,
+    /// Occurs when a character is found where the grammar doesn't allow it.
+    #[error("Unexpected character '{0}'")]
+    UnexpectedCharacter(char),
+    /// Occurs when the input ends before a complete number was read, e.g. `1.2e` or `1.2*10^`.
+    #[error("Unexpected end of input")]
+    UnexpectedEnd,
+    /// Occurs when the number is too large or too small to be represented as a `StandardForm`.
+    #[error("Number is out of the representable range")]
+    OutOfRange,
+    /// Occurs when the input is longer than the limit set by `ParseOptions::max_length`.
+    #[error("Input is longer than the maximum of {0} bytes")]
+    TooLong(usize),
+    /// Occurs when the written exponent exceeds the limit set by `ParseOptions::max_exponent`.
+    #[error("Exponent exceeds the maximum magnitude of {0}")]
+    ExponentLimit(u32),
+    /// Wraps an error with the 1-based line and column where it occurred, as reported by `StandardFormReader`.
+    #[error("{source} at line {line}, column {column}")]
+    At {
+        /// Line of the offending number, starting at 1
+        line : usize,
+        /// Column of the offending character in characters, starting at 1
+        column : usize,
+        /// The error that occurred at this position
+        source : Box<ParsingStandardFormError>
+    },
+    /// Occurs when `StandardFormReader` fails to read from its underlying reader.
+    #[error("Failed reading input due to {0}")]
+    Io(Arc<io::Error>),
     /// Only occurs when `StandardFrom::try_from(&[u8])` is done 
     #[error("Given bytes are not formatted in UTF-8")]
     InvalidBytes(#[from] Utf8Error),
@@ -23,4 +58,148 @@ pub enum ParsingStandardFormError {
     #[cfg(feature = "num")]
     #[error("Invalid radix : Only radix 10 is supported")]
     InvalidRadix
-}
\ No newline at end of file
+}
+
+/// A [`ParsingStandardFormError`] together with where and why it occurred in the input.
+///
+/// Returned by [`StandardForm::parse_with_diagnostics`](crate::StandardForm::parse_with_diagnostics).
+/// Its `Display` renders the input with a caret under the offending bytes, the expected token and, where possible, a
+/// suggested fix.
+///
+/// ```
+/// use standardform::StandardForm;
+///
+/// let diagnostic = StandardForm::parse_with_diagnostics("1.2*10*5").unwrap_err();
+///
+/// assert_eq!(diagnostic.to_string(), "\
+/// Unexpected character '*' at 6..7
+///   1.2*10*5
+///         ^
+///   expected '^' or '**'
+///   help: did you mean 10^ instead of 10*?");
+/// ```
+#[derive(Debug,Clone)]
+pub struct ParsingStandardFormDiagnostic {
+    input : String,
+    span : Range<usize>,
+    error : ParsingStandardFormError,
+    expected : &'static str,
+    suggestion : Option<&'static str>
+}
+
+impl ParsingStandardFormDiagnostic {
+    pub(crate) fn new(input : &str,span : Range<usize>,error : ParsingStandardFormError,expected : &'static str) -> Self {
+        Self { input : input.to_string() , span , error , expected , suggestion : None }
+    }
+
+    pub(crate) fn with_suggestion(mut self,suggestion : Option<&'static str>) -> Self {
+        self.suggestion = suggestion;
+        self
+    }
+
+    /// Returns the input that failed to parse.
+    ///
+    /// Inputs rejected as [`ParsingStandardFormError::TooLong`] are cut off at the limit, so the span reaches past them.
+    #[must_use]
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// Returns the byte range of `input` that caused the error.
+    ///
+    /// The range is empty when the input ended too early.
+    #[must_use]
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    /// Returns the underlying error.
+    #[must_use]
+    pub const fn error(&self) -> &ParsingStandardFormError {
+        &self.error
+    }
+
+    /// Returns a description of the token that was expected at `span`.
+    #[must_use]
+    pub const fn expected(&self) -> &'static str {
+        self.expected
+    }
+
+    /// Returns a suggested fix, if one is known for this mistake.
+    #[must_use]
+    pub const fn suggestion(&self) -> Option<&'static str> {
+        self.suggestion
+    }
+}
+
+impl fmt::Display for ParsingStandardFormDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // the caret is drawn under whole characters, even if the span starts or ends inside one
+        let start = floor_char_boundary(&self.input,self.span.start);
+        let end = floor_char_boundary(&self.input,self.span.end).max(start);
+
+        let offset = self.input[..start].chars().count();
+        let width = self.input[start..end].chars().count().max(1);
+
+        writeln!(f,"{} at {}..{}",self.error,self.span.start,self.span.end)?;
+        writeln!(f,"  {}",self.input)?;
+        writeln!(f,"  {}{}"," ".repeat(offset),"^".repeat(width))?;
+        write!(f,"  expected {}",self.expected)?;
+
+        if let Some(suggestion) = self.suggestion {
+            write!(f,"\n  help: {suggestion}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the largest character boundary of `input` that is at most `index`.
+pub(crate) fn floor_char_boundary(input : &str,index : usize) -> usize {
+    (0..=index.min(input.len())).rev().find(|index| input.is_char_boundary(*index)).unwrap_or(0)
+}
+
+impl std::error::Error for ParsingStandardFormDiagnostic {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl From<ParsingStandardFormDiagnostic> for ParsingStandardFormError {
+    fn from(diagnostic: ParsingStandardFormDiagnostic) -> Self {
+        diagnostic.error
+    }
+}
+
+/// Occurs when an inverse trigonometric or hyperbolic function is given an argument outside its domain, e.g. `asin(2)`.
+#[derive(Error,Debug,Clone,Copy,PartialEq,Eq,Hash)]
+#[error("{function} is only defined for arguments in {domain}")]
+pub struct DomainError {
+    function : &'static str,
+    domain : &'static str
+}
+
+impl DomainError {
+    pub(crate) const fn new(function : &'static str,domain : &'static str) -> Self {
+        Self { function , domain }
+    }
+
+    /// Returns the name of the function, e.g. `"asin"`.
+    #[must_use]
+    pub const fn function(&self) -> &'static str {
+        self.function
+    }
+
+    /// Returns the interval of valid arguments, e.g. `"[-1, 1]"`.
+    #[must_use]
+    pub const fn domain(&self) -> &'static str {
+        self.domain
+    }
+}
+
+#[cfg(feature = "js")]
+impl From<DomainError> for wasm_bindgen::JsValue {
+    fn from(error: DomainError) -> Self {
+        wasm_bindgen::JsValue::from_str(&error.to_string())
+    }
+}
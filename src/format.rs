@@ -0,0 +1,180 @@
+use core::fmt;
+use core::ops::Deref;
+
+use crate::StandardForm;
+
+/// A stack buffer holding a formatted [`StandardForm`], returned by [`StandardForm::to_scientific_buffer`] and
+/// [`StandardForm::to_engineering_buffer`].
+///
+/// It can hold up to [`StandardForm::MAX_FORMATTED_LENGTH`] bytes and dereferences to `str`.
+#[derive(Clone,Copy)]
+pub struct StandardFormBuffer {
+    bytes : [u8;StandardForm::MAX_FORMATTED_LENGTH],
+    length : usize
+}
+
+impl StandardFormBuffer {
+    pub(crate) const fn new() -> Self {
+        Self { bytes : [0;StandardForm::MAX_FORMATTED_LENGTH] , length : 0 }
+    }
+
+    /// Returns the formatted number.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.bytes[..self.length]).expect("only whole `str`s are written to the buffer")
+    }
+}
+
+impl fmt::Write for StandardFormBuffer {
+    fn write_str(&mut self, string: &str) -> fmt::Result {
+        let bytes = self.bytes.get_mut(self.length..self.length + string.len()).ok_or(fmt::Error)?;
+        bytes.copy_from_slice(string.as_bytes());
+        self.length += string.len();
+        Ok(())
+    }
+}
+
+impl Deref for StandardFormBuffer {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for StandardFormBuffer {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for StandardFormBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Debug for StandardFormBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(),f)
+    }
+}
+
+/// Writes into a byte slice, failing once it is full.
+struct SliceWriter<'b> {
+    bytes : &'b mut [u8],
+    length : usize
+}
+
+impl fmt::Write for SliceWriter<'_> {
+    fn write_str(&mut self, string: &str) -> fmt::Result {
+        let bytes = self.bytes.get_mut(self.length..self.length + string.len()).ok_or(fmt::Error)?;
+        bytes.copy_from_slice(string.as_bytes());
+        self.length += string.len();
+        Ok(())
+    }
+}
+
+impl StandardForm {
+    /// The longest output of the `write_*`, `format_*` and `to_*_buffer` methods in bytes, e.g. `-1.2345678901234567*10^-2147483648`.
+    pub const MAX_FORMATTED_LENGTH : usize = "-1.2345678901234567*10^-2147483648".len();
+
+    /// Writes the number in scientific notation, e.g. `1.5e3`, like [`StandardForm::to_scientific_notation`] but without allocating.
+    ///
+    /// The mantissa is written with the shortest digits that round-trip to the same `f64`.
+    pub fn write_scientific<W : fmt::Write>(&self,writer : &mut W) -> fmt::Result {
+        write!(writer,"{}e{}",self.mantissa(),self.exponent())
+    }
+
+    /// Writes the number in engineering notation, e.g. `1.5*10^3`, like [`StandardForm::to_engineering_notation`] but without allocating.
+    pub fn write_engineering<W : fmt::Write>(&self,writer : &mut W) -> fmt::Result {
+        write!(writer,"{}*10^{}",self.mantissa(),self.exponent())
+    }
+
+    /// Formats the number in scientific notation into a stack buffer.
+    ///
+    /// ```
+    /// use standardform::StandardForm;
+    ///
+    /// let buffer = StandardForm::new(-1.5,-3).to_scientific_buffer();
+    /// assert_eq!(&*buffer, "-1.5e-3");
+    /// ```
+    #[must_use]
+    pub fn to_scientific_buffer(&self) -> StandardFormBuffer {
+        let mut buffer = StandardFormBuffer::new();
+        self.write_scientific(&mut buffer).expect("`MAX_FORMATTED_LENGTH` bytes fit any number");
+        buffer
+    }
+
+    /// Formats the number in engineering notation into a stack buffer.
+    #[must_use]
+    pub fn to_engineering_buffer(&self) -> StandardFormBuffer {
+        let mut buffer = StandardFormBuffer::new();
+        self.write_engineering(&mut buffer).expect("`MAX_FORMATTED_LENGTH` bytes fit any number");
+        buffer
+    }
+
+    /// Formats the number in scientific notation into `bytes`, returning the written part.
+    ///
+    /// Fails if `bytes` is too short, which can't happen once it is [`StandardForm::MAX_FORMATTED_LENGTH`] long.
+    pub fn format_scientific<'b>(&self,bytes : &'b mut [u8]) -> Result<&'b str,fmt::Error> {
+        let mut writer = SliceWriter { bytes , length : 0 };
+        self.write_scientific(&mut writer)?;
+        Ok(core::str::from_utf8(&writer.bytes[..writer.length]).expect("only whole `str`s are written to the slice"))
+    }
+
+    /// Formats the number in engineering notation into `bytes`, returning the written part.
+    pub fn format_engineering<'b>(&self,bytes : &'b mut [u8]) -> Result<&'b str,fmt::Error> {
+        let mut writer = SliceWriter { bytes , length : 0 };
+        self.write_engineering(&mut writer)?;
+        Ok(core::str::from_utf8(&writer.bytes[..writer.length]).expect("only whole `str`s are written to the slice"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn buffers() {
+        let standard_form = StandardForm::new(6.02,23);
+        assert_eq!(standard_form.to_scientific_buffer().as_str(), "6.02e23");
+        assert_eq!(standard_form.to_engineering_buffer().as_str(), "6.02*10^23");
+        assert_eq!(standard_form.to_scientific_buffer().as_str(), standard_form.to_scientific_notation());
+        assert_eq!(standard_form.to_engineering_buffer().as_str(), standard_form.to_engineering_notation());
+    }
+
+    #[test]
+    fn slices() {
+        let mut bytes = [0;StandardForm::MAX_FORMATTED_LENGTH];
+        assert_eq!(StandardForm::new(1.5,3).format_engineering(&mut bytes), Ok("1.5*10^3"));
+
+        let mut bytes = [0;6];
+        assert_eq!(StandardForm::new(1.5,3).format_scientific(&mut bytes), Ok("1.5e3"));
+        assert_eq!(StandardForm::new(1.25,3).format_scientific(&mut bytes), Ok("1.25e3"));
+        assert!(StandardForm::new(1.125,3).format_scientific(&mut bytes).is_err());
+    }
+
+    #[test]
+    fn longest() {
+        let standard_form = StandardForm::parse_fast("-1.2345678901234567e-2147483648").unwrap();
+        assert_eq!(standard_form.to_engineering_buffer().len(), StandardForm::MAX_FORMATTED_LENGTH);
+    }
+
+    #[test]
+    fn shortest_round_trip() {
+        let mut state : u64 = 0x9e37_79b9_7f4a_7c15;
+
+        for _ in 0..10_000 {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+
+            let mantissa = 1.0 + (state >> 11) as f64 / (1_u64 << 53) as f64 * 9.0;
+            let standard_form = StandardForm::new_unchecked(mantissa,state as i32);
+
+            let buffer = standard_form.to_scientific_buffer();
+            assert_eq!(StandardForm::parse_fast(&buffer), Ok(standard_form), "{buffer}");
+        }
+    }
+}
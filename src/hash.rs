@@ -1,11 +1,154 @@
+use core::borrow::Borrow;
+use core::cmp::Ordering;
 use core::hash::{Hash, Hasher};
-use ordered_float::OrderedFloat;
 
 use crate::StandardForm;
 
-impl Hash for StandardForm { 
+/// Hashes the canonical form of the number, i.e. the bits of its mantissa followed by its exponent.
+///
+/// Every `StandardForm` is kept canonical, with zero as `0e0`, no negative zero and a single NaN, so numbers that
+/// compare equal hash identically however they were constructed, e.g. `StandardForm::from(-0.0)` and
+/// `StandardForm::new(0.0, 5)`, or `StandardForm::from(10_u8)` and `StandardForm::new(1.0, 1)`.
+impl Hash for StandardForm {
     fn hash<H>(&self, state: &mut H) where H: Hasher {
-        OrderedFloat(*self.mantissa()).hash(state);
+        self.mantissa().to_bits().hash(state);
         self.exponent().hash(state);
     }
-}
\ No newline at end of file
+}
+
+/// A number that can be used to look up a `HashMap` or `BTreeMap` keyed by `StandardForm`.
+///
+/// `StandardForm` implements `Borrow<dyn StandardFormKey>`, so a map can be queried with any primitive,
+/// which is compared and hashed exactly like the `StandardForm` it converts into.
+///
+/// ```
+/// use std::collections::HashMap;
+/// use standardform::{StandardForm,StandardFormKey};
+///
+/// let mut map = HashMap::new();
+/// map.insert(StandardForm::new(1.5,2),"one hundred and fifty");
+///
+/// assert_eq!(map.get(&150_u32 as &dyn StandardFormKey), Some(&"one hundred and fifty"));
+/// assert_eq!(map.get(&150.0 as &dyn StandardFormKey), Some(&"one hundred and fifty"));
+/// ```
+pub trait StandardFormKey {
+    /// Returns the number as a `StandardForm`.
+    fn to_standard_form(&self) -> StandardForm;
+}
+
+impl StandardFormKey for StandardForm {
+    fn to_standard_form(&self) -> StandardForm {
+        *self
+    }
+}
+
+macro_rules! keys {
+    ($($t : ty),*) => {
+        $(
+            impl StandardFormKey for $t {
+                fn to_standard_form(&self) -> StandardForm {
+                    (*self).into()
+                }
+            }
+        )*
+    };
+}
+
+keys!(u8,u16,u32,u64,u128,usize,i8,i16,i32,i64,i128,isize,f32,f64);
+
+impl<'a> Borrow<dyn StandardFormKey + 'a> for StandardForm {
+    fn borrow(&self) -> &(dyn StandardFormKey + 'a) {
+        self
+    }
+}
+
+impl Hash for dyn StandardFormKey + '_ {
+    fn hash<H>(&self, state: &mut H) where H: Hasher {
+        self.to_standard_form().hash(state);
+    }
+}
+
+impl PartialEq for dyn StandardFormKey + '_ {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_standard_form() == other.to_standard_form()
+    }
+}
+
+impl Eq for dyn StandardFormKey + '_ {}
+
+impl PartialOrd for dyn StandardFormKey + '_ {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for dyn StandardFormKey + '_ {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.to_standard_form().cmp(&other.to_standard_form())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::{BTreeMap,HashMap,HashSet};
+    use std::hash::BuildHasher;
+
+    #[test]
+    fn equal_numbers_hash_equal() {
+        let numbers : HashSet<StandardForm> = [
+            StandardForm::new(10.0, 0),
+            StandardForm::new(1.0, 1),
+            StandardForm::new(0.0, 5),
+            StandardForm::new(-0.0, 0),
+            StandardForm::from(-0.0),
+            StandardForm::new(f64::NAN, 3),
+            StandardForm::new(-f64::NAN, 0),
+            StandardForm::from(f32::NAN),
+        ].into_iter().collect();
+
+        assert_eq!(numbers.len(), 3);
+    }
+
+    #[test]
+    fn hash_is_independent_of_construction() {
+        let state = std::hash::RandomState::new();
+
+        for (value,text,mantissa,exponent) in [(3e-4, "0.0003", 3.0, -4), (0.1, "1e-1", 10.0, -2), (-0.0, "0e9", 0.0, 7)] {
+            let hash = state.hash_one(StandardForm::from(value));
+
+            assert_eq!(state.hash_one(StandardForm::try_from(text).unwrap()), hash, "{text}");
+            assert_eq!(state.hash_one(StandardForm::new(mantissa,exponent)), hash, "{text}");
+        }
+    }
+
+    #[test]
+    fn keys_hash_like_standard_forms() {
+        let state = std::hash::RandomState::new();
+
+        let keys : [(&dyn StandardFormKey,StandardForm);3] = [(&10_u8, StandardForm::new(1.0, 1)), (&-0.0, StandardForm::new(0.0, 0)), (&f64::NAN, StandardForm::new(f64::NAN, 0))];
+
+        for (key,standard_form) in keys {
+            assert_eq!(state.hash_one(key), state.hash_one(standard_form));
+            assert!(key == standard_form.borrow());
+        }
+    }
+
+    #[test]
+    fn maps() {
+        let mut map = HashMap::new();
+        let _ = map.insert(StandardForm::new(2.5, -1), 'a');
+        let _ = map.insert(StandardForm::new(0.0, 0), 'b');
+
+        let keys : [(&dyn StandardFormKey,Option<&char>);4] = [(&0.25, Some(&'a')), (&-0.0, Some(&'b')), (&0_i64, Some(&'b')), (&1_i64, None)];
+
+        for (key,value) in keys {
+            assert_eq!(map.get(key), value);
+        }
+
+        let map : BTreeMap<_,_> = map.into_iter().collect();
+        let quarter : &dyn StandardFormKey = &0.25_f32;
+        assert_eq!(map.get(quarter), Some(&'a'));
+    }
+}
@@ -0,0 +1,142 @@
+use core::fmt::{self,Write};
+
+use crate::{StandardForm,StandardFormBuffer};
+
+/// Represents the errors returned when converting a `StandardForm` into an integer with `TryFrom`.
+#[derive(Debug,Clone,Copy,PartialEq,Eq,Hash)]
+pub enum IntegerConversionError {
+    /// Occurs when the number is an integer but too large in magnitude for the target type, including infinities.
+    Overflow,
+    /// Occurs when a negative number is converted into an unsigned type.
+    Negative,
+    /// Occurs when the number has a fractional part, or is NaN.
+    NotAnInteger
+}
+
+impl fmt::Display for IntegerConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Overflow => write!(f,"Number is too large for the target integer type"),
+            Self::Negative => write!(f,"Negative number can't be converted into an unsigned integer"),
+            Self::NotAnInteger => write!(f,"Number is not an integer")
+        }
+    }
+}
+
+#[cfg(feature="std")]
+impl std::error::Error for IntegerConversionError {}
+
+impl StandardForm {
+    /// Returns whether the number is negative and its exact magnitude, reading the mantissa as its shortest decimal
+    /// digits like every other operation does, so `1.1e1` is exactly `11` rather than the nearest `f64`.
+    fn integer_magnitude(&self) -> Result<(bool,u128),IntegerConversionError> {
+        let negative = self.mantissa().is_sign_negative();
+
+        if self.mantissa().is_nan() {
+            return Err(IntegerConversionError::NotAnInteger);
+        }
+
+        if self.mantissa().is_infinite() {
+            return Err(IntegerConversionError::Overflow);
+        }
+
+        if *self.mantissa() == 0.0 {
+            return Ok((false,0));
+        }
+
+        let mut buffer = StandardFormBuffer::new();
+        write!(buffer,"{}",self.mantissa().abs()).expect("a mantissa fits in the buffer");
+
+        let (integer,fraction) = buffer.split_once('.').unwrap_or((&buffer,""));
+        // at most 17 significant digits, so this can't overflow
+        let digits = integer.bytes().chain(fraction.bytes()).fold(0_u128,|digits,digit| digits * 10 + u128::from(digit - b'0'));
+        let scale = self.exponent().saturating_sub(fraction.len() as i32);
+
+        // shortest digits never end in a zero after the decimal point, so a negative scale always leaves a fraction
+        if scale < 0 {
+            return Err(IntegerConversionError::NotAnInteger);
+        }
+
+        let magnitude = 10_u128.checked_pow(scale.unsigned_abs())
+            .and_then(|power| digits.checked_mul(power))
+            .ok_or(IntegerConversionError::Overflow)?;
+
+        Ok((negative,magnitude))
+    }
+}
+
+macro_rules! integers {
+    (signed => $($t : ty),*) => {
+        $(
+            /// Converts exactly, failing if the number has a fractional part or doesn't fit.
+            impl TryFrom<StandardForm> for $t {
+                type Error = IntegerConversionError;
+
+                fn try_from(value: StandardForm) -> Result<Self, Self::Error> {
+                    let (negative,magnitude) = value.integer_magnitude()?;
+
+                    let value = match negative {
+                        true => 0_i128.checked_sub_unsigned(magnitude),
+                        false => i128::try_from(magnitude).ok()
+                    };
+
+                    value.and_then(|value| <$t>::try_from(value).ok()).ok_or(IntegerConversionError::Overflow)
+                }
+            }
+        )*
+    };
+    (unsigned => $($t : ty),*) => {
+        $(
+            /// Converts exactly, failing if the number is negative, has a fractional part or doesn't fit.
+            impl TryFrom<StandardForm> for $t {
+                type Error = IntegerConversionError;
+
+                fn try_from(value: StandardForm) -> Result<Self, Self::Error> {
+                    match value.integer_magnitude() {
+                        Ok((true,_)) => Err(IntegerConversionError::Negative),
+                        Ok((false,magnitude)) => <$t>::try_from(magnitude).map_err(|_| IntegerConversionError::Overflow),
+                        // -inf is negative before it is too large
+                        Err(IntegerConversionError::Overflow) if value.mantissa().is_sign_negative() => Err(IntegerConversionError::Negative),
+                        Err(error) => Err(error)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+integers!(signed => i8,i16,i32,i64,i128,isize);
+integers!(unsigned => u8,u16,u32,u64,u128,usize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact() {
+        assert_eq!(u8::try_from(StandardForm::new(2.55,2)), Ok(255));
+        assert_eq!(i8::try_from(StandardForm::new(-1.28,2)), Ok(-128));
+        assert_eq!(i64::try_from(StandardForm::new(1.1,1)), Ok(11));
+        assert_eq!(u64::try_from(StandardForm::new(1.2345678901234567,16)), Ok(12_345_678_901_234_567));
+        assert_eq!(u128::try_from(StandardForm::new(3.4,38)), Ok(340_000_000_000_000_000_000_000_000_000_000_000_000));
+        assert_eq!(i128::try_from(StandardForm::new(-1.7,38)), Ok(-170_000_000_000_000_000_000_000_000_000_000_000_000));
+        assert_eq!(usize::try_from(StandardForm::new(0.0,0)), Ok(0));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(u8::try_from(StandardForm::new(2.56,2)), Err(IntegerConversionError::Overflow));
+        assert_eq!(i8::try_from(StandardForm::new(-1.29,2)), Err(IntegerConversionError::Overflow));
+        assert_eq!(u64::try_from(StandardForm::new(1.0,100)), Err(IntegerConversionError::Overflow));
+        assert_eq!(u128::try_from(StandardForm::new(3.5,38)), Err(IntegerConversionError::Overflow));
+        assert_eq!(i32::try_from(StandardForm::new(f64::INFINITY,0)), Err(IntegerConversionError::Overflow));
+
+        assert_eq!(u64::try_from(StandardForm::new(-1.0,0)), Err(IntegerConversionError::Negative));
+        assert_eq!(u64::try_from(StandardForm::new(-1.0,100)), Err(IntegerConversionError::Negative));
+        assert_eq!(u32::try_from(StandardForm::new(f64::NEG_INFINITY,0)), Err(IntegerConversionError::Negative));
+
+        assert_eq!(i64::try_from(StandardForm::new(1.5,0)), Err(IntegerConversionError::NotAnInteger));
+        assert_eq!(u64::try_from(StandardForm::new(1.0,-100)), Err(IntegerConversionError::NotAnInteger));
+        assert_eq!(i64::try_from(StandardForm::new(f64::NAN,0)), Err(IntegerConversionError::NotAnInteger));
+    }
+}
@@ -28,6 +28,18 @@ uniffi::setup_scaffolding!();
 mod sf;
 pub use self::sf::*;
 
+mod fast;
+pub use self::fast::*;
+
+mod format;
+pub use self::format::*;
+
+mod integer;
+pub use self::integer::*;
+
+mod sum;
+pub use self::sum::*;
+
 // needed for thiserror::Error macro
 #[cfg(feature = "std")]
 mod error;
@@ -35,6 +47,42 @@ mod error;
 #[cfg(feature = "std")]
 pub use self::error::*;
 
+#[cfg(feature = "std")]
+mod math;
+
+#[cfg(feature = "std")]
+mod trig;
+
+#[cfg(feature = "std")]
+pub use self::trig::*;
+
+#[cfg(feature = "std")]
+mod combinatorics;
+
+#[cfg(feature = "std")]
+mod parse;
+
+#[cfg(feature = "std")]
+pub use self::parse::*;
+
+#[cfg(feature = "std")]
+mod scan;
+
+#[cfg(feature = "std")]
+pub use self::scan::*;
+
+#[cfg(feature = "std")]
+mod reader;
+
+#[cfg(feature = "std")]
+pub use self::reader::*;
+
+#[cfg(feature = "std")]
+mod dialect;
+
+#[cfg(feature = "std")]
+pub use self::dialect::*;
+
 #[cfg(feature = "num")]
 mod num;
 
@@ -48,7 +96,13 @@ mod hash;
 pub use hash::*;
 
 #[cfg(feature = "nom")]
-mod nom;
+pub mod nom;
 
 #[cfg(feature = "nom")]
-pub use nom::*;
\ No newline at end of file
+pub use self::nom::{parse_standard_form_with_required_exponent,parse_standard_form_with_optional_exponent};
+
+#[cfg(feature = "winnow")]
+pub mod winnow;
+
+#[cfg(feature = "chumsky")]
+pub mod chumsky;
\ No newline at end of file
@@ -0,0 +1,314 @@
+use core::f64::consts::{LN_10,LOG10_E,LOG2_10};
+
+use crate::StandardForm;
+use crate::sf::pow10;
+
+/// Returns `10^exponent` for an exponent given as an `f64`, splitting it into an integer exponent and a fractional
+/// part that becomes the mantissa, so the result is only limited by the range of the exponent.
+pub(crate) fn power_of_ten(exponent : f64) -> StandardForm {
+    if exponent.is_nan() {
+        return StandardForm::new(f64::NAN,0);
+    }
+
+    // beyond the range of i64 the result is infinity or zero anyway
+    let exponent = exponent.clamp(-1.0e18,1.0e18);
+    let integer = exponent.floor();
+
+    StandardForm::saturating_new(10.0_f64.powf(exponent - integer),integer as i64)
+}
+
+/// Multiplies two mantissas, carrying the exponents as `i64` so they can't overflow.
+fn multiply((mantissa,exponent) : (f64,i64),(other_mantissa,other_exponent) : (f64,i64)) -> (f64,i64) {
+    let product = mantissa * other_mantissa;
+
+    match product.abs() >= 10.0 {
+        true => (product / 10.0,exponent.saturating_add(other_exponent).saturating_add(1)),
+        false => (product,exponent.saturating_add(other_exponent))
+    }
+}
+
+/// Functions evaluated on the mantissa and exponent separately, so results beyond the range of `f64` such as `10^500`
+/// are still representable. Results whose exponent doesn't fit in an `i32` become infinity or zero like `f64` does.
+impl StandardForm {
+    /// Raises the number to an integer power by repeated squaring.
+    ///
+    /// ```
+    /// use standardform::StandardForm;
+    ///
+    /// assert_eq!(StandardForm::new(2.0,100).powi(5), StandardForm::new(3.2,501));
+    /// ```
+    #[must_use]
+    pub fn powi(self,n : i32) -> Self {
+        if n == 0 {
+            return Self::new(1.0,0);
+        }
+
+        if *self.mantissa() == 0.0 || !self.mantissa().is_finite() {
+            return Self::new(self.mantissa().powi(n),0);
+        }
+
+        // f64 is more accurate within its own range
+        let result = self.into_f64().powi(n);
+        if result.is_normal() {
+            return result.into();
+        }
+
+        let mut base = (*self.mantissa(),i64::from(*self.exponent()));
+        let mut result = (1.0,0_i64);
+        let mut remaining = n.unsigned_abs();
+
+        while remaining > 0 {
+            if remaining & 1 == 1 {
+                result = multiply(result,base);
+            }
+
+            remaining >>= 1;
+
+            if remaining > 0 {
+                base = multiply(base,base);
+            }
+        }
+
+        let (mantissa,exponent) = match n < 0 {
+            true => (1.0 / result.0,result.1.saturating_neg()),
+            false => result
+        };
+
+        Self::saturating_new(mantissa,exponent)
+    }
+
+    /// Raises the number to any power, using [`StandardForm::powi`] for integer powers and logarithms otherwise.
+    /// A negative number raised to a non-integer power is NaN.
+    #[must_use]
+    pub fn pow(self,n : Self) -> Self {
+        if let Ok(n) = i32::try_from(n) {
+            return self.powi(n);
+        }
+
+        if *self.mantissa() == 0.0 || !self.mantissa().is_finite() || !n.mantissa().is_finite() {
+            return Self::new(f64::from(self).powf(n.into_f64()),0);
+        }
+
+        match *self.mantissa() < 0.0 {
+            true => Self::new(f64::NAN,0),
+            false => power_of_ten(n.into_f64() * (f64::from(*self.exponent()) + self.mantissa().log10()))
+        }
+    }
+
+    /// Returns the square root, halving the exponent exactly so `sqrt(1e-300)` is `1e-150`.
+    #[must_use]
+    pub fn sqrt(self) -> Self {
+        self.nth_root(2)
+    }
+
+    /// Returns the cube root, which is negative for negative numbers.
+    #[must_use]
+    pub fn cbrt(self) -> Self {
+        self.nth_root(3)
+    }
+
+    /// Returns the `n`th root. Even roots of negative numbers and the zeroth root are NaN.
+    ///
+    /// ```
+    /// use standardform::StandardForm;
+    ///
+    /// assert_eq!(StandardForm::new(1.0,-300).nth_root(4), StandardForm::new(1.0,-75));
+    /// ```
+    #[must_use]
+    pub fn nth_root(self,n : i32) -> Self {
+        if n == 0 || self.mantissa().is_nan() || (*self.mantissa() < 0.0 && n % 2 == 0) {
+            return Self::new(f64::NAN,0);
+        }
+
+        if n < 0 {
+            return Self::new(1.0,0) / self.nth_root_of_magnitude(n.unsigned_abs()).with_sign_of(self);
+        }
+
+        self.nth_root_of_magnitude(n.unsigned_abs()).with_sign_of(self)
+    }
+
+    fn nth_root_of_magnitude(self,n : u32) -> Self {
+        let magnitude = self.mantissa().abs();
+
+        if magnitude == 0.0 || magnitude.is_infinite() {
+            return Self::new(magnitude,0);
+        }
+
+        // split the exponent so the remainder can join the mantissa, keeping the root of the mantissa in [1,10)
+        let n = i64::from(n);
+        let exponent = i64::from(*self.exponent());
+        let (quotient,remainder) = (exponent.div_euclid(n),exponent.rem_euclid(n));
+
+        let mantissa = match n {
+            2 => (magnitude * pow10(remainder as i32)).sqrt(),
+            3 => (magnitude * pow10(remainder as i32)).cbrt(),
+            _ => 10.0_f64.powf((magnitude.log10() + remainder as f64) / n as f64)
+        };
+
+        Self::saturating_new(mantissa,quotient)
+    }
+
+    fn with_sign_of(self,other : Self) -> Self {
+        match *other.mantissa() < 0.0 {
+            true => -self,
+            false => self
+        }
+    }
+
+    /// Returns `e^self`.
+    #[must_use]
+    pub fn exp(self) -> Self {
+        let value = self.into_f64();
+
+        // f64 is more accurate within its own range
+        match value.abs() < 700.0 {
+            true => value.exp().into(),
+            false => power_of_ten(value * LOG10_E)
+        }
+    }
+
+    /// Returns `10^self`, which is exact for integers.
+    ///
+    /// ```
+    /// use standardform::StandardForm;
+    ///
+    /// assert_eq!(StandardForm::new(5.0,2).exp10(), StandardForm::new(1.0,500));
+    /// ```
+    #[must_use]
+    pub fn exp10(self) -> Self {
+        power_of_ten(self.into_f64())
+    }
+
+    /// Returns the natural logarithm.
+    #[must_use]
+    pub fn ln(self) -> Self {
+        match *self.mantissa() > 0.0 && self.mantissa().is_finite() {
+            true => (f64::from(*self.exponent()) * LN_10 + self.mantissa().ln()).into(),
+            false => self.mantissa().ln().into()
+        }
+    }
+
+    /// Returns the base 10 logarithm, which is exact for powers of ten.
+    #[must_use]
+    pub fn log10(self) -> Self {
+        match *self.mantissa() > 0.0 && self.mantissa().is_finite() {
+            true => (f64::from(*self.exponent()) + self.mantissa().log10()).into(),
+            false => self.mantissa().log10().into()
+        }
+    }
+
+    /// Returns the base 2 logarithm.
+    #[must_use]
+    pub fn log2(self) -> Self {
+        match *self.mantissa() > 0.0 && self.mantissa().is_finite() {
+            true => (f64::from(*self.exponent()) * LOG2_10 + self.mantissa().log2()).into(),
+            false => self.mantissa().log2().into()
+        }
+    }
+
+    /// Returns `sqrt(self^2 + other^2)` without squaring the exponents.
+    ///
+    /// ```
+    /// use standardform::StandardForm;
+    ///
+    /// assert_eq!(StandardForm::new(3.0,200).hypot(StandardForm::new(4.0,200)), StandardForm::new(5.0,200));
+    /// ```
+    #[must_use]
+    pub fn hypot(self,other : Self) -> Self {
+        if !self.mantissa().is_finite() || !other.mantissa().is_finite() {
+            return Self::new(self.mantissa().hypot(*other.mantissa()),0);
+        }
+
+        if *self.mantissa() == 0.0 {
+            return Self::new(other.mantissa().abs(),*other.exponent());
+        }
+
+        if *other.mantissa() == 0.0 {
+            return Self::new(self.mantissa().abs(),*self.exponent());
+        }
+
+        let exponent = *self.exponent().max(other.exponent());
+        let x = self.mantissa() * pow10(self.exponent().saturating_sub(exponent));
+        let y = other.mantissa() * pow10(other.exponent().saturating_sub(exponent));
+
+        Self::saturating_new(x.hypot(y),i64::from(exponent))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn powers() {
+        assert_eq!(StandardForm::new(2.0,100).powi(5), StandardForm::new(3.2,501));
+        assert_eq!(StandardForm::new(1.0,1).powi(500), StandardForm::new(1.0,500));
+        assert_eq!(StandardForm::new(1.0,1).powi(-500), StandardForm::new(1.0,-500));
+        assert_eq!(StandardForm::new(-2.0,0).powi(3), StandardForm::new(-8.0,0));
+        assert_eq!(StandardForm::new(2.0,0).powi(-1), StandardForm::new(5.0,-1));
+        assert_eq!(StandardForm::new(f64::NAN,0).powi(0), StandardForm::new(1.0,0));
+        assert_eq!(StandardForm::new(0.0,0).powi(-1), StandardForm::new(f64::INFINITY,0));
+
+        // the exponent saturates instead of overflowing
+        assert_eq!(StandardForm::new(1.0,1_000_000_000).powi(3), StandardForm::new(f64::INFINITY,0));
+        assert_eq!(StandardForm::new(1.0,-1_000_000_000).powi(3), StandardForm::new(0.0,0));
+        assert_eq!(StandardForm::new(-1.0,1_000_000_000).powi(3), StandardForm::new(f64::NEG_INFINITY,0));
+
+        assert_eq!(StandardForm::new(1.0,2).pow(StandardForm::new(2.5,2)), StandardForm::new(1.0,500));
+        assert_eq!(StandardForm::new(1.0,4).pow(StandardForm::new(5.0,-1)), StandardForm::new(1.0,2));
+        assert!(StandardForm::new(-1.0,4).pow(StandardForm::new(5.0,-1)).mantissa().is_nan());
+        assert_eq!(StandardForm::new(0.0,0).pow(StandardForm::new(5.0,-1)), StandardForm::new(0.0,0));
+    }
+
+    #[test]
+    fn roots() {
+        assert_eq!(StandardForm::new(1.0,-300).sqrt(), StandardForm::new(1.0,-150));
+        assert_eq!(StandardForm::new(1.0,-301).sqrt(), StandardForm::new(f64::sqrt(10.0),-151));
+        assert_eq!(StandardForm::new(9.0,1000).sqrt(), StandardForm::new(3.0,500));
+        assert!(StandardForm::new(-4.0,0).sqrt().mantissa().is_nan());
+
+        assert_eq!(StandardForm::new(8.0,600).cbrt(), StandardForm::new(2.0,200));
+        assert_eq!(StandardForm::new(-2.7,-299).cbrt(), StandardForm::new(-3.0,-100));
+
+        assert_eq!(StandardForm::new(1.0,-300).nth_root(4), StandardForm::new(1.0,-75));
+        assert_eq!(StandardForm::new(1.0,500).nth_root(-5), StandardForm::new(1.0,-100));
+        assert!(StandardForm::new(1.0,0).nth_root(0).mantissa().is_nan());
+        assert_eq!(StandardForm::new(0.0,0).nth_root(7), StandardForm::new(0.0,0));
+    }
+
+    #[test]
+    fn exponentials() {
+        assert_eq!(StandardForm::new(5.0,2).exp10(), StandardForm::new(1.0,500));
+        assert_eq!(StandardForm::new(-5.0,2).exp10(), StandardForm::new(1.0,-500));
+        assert_eq!(StandardForm::new(2.5,0).exp10(), StandardForm::new(f64::sqrt(10.0),2));
+        assert_eq!(StandardForm::new(1.0,10).exp10(), StandardForm::new(f64::INFINITY,0));
+        assert_eq!(StandardForm::new(-1.0,10).exp10(), StandardForm::new(0.0,0));
+
+        assert_eq!(StandardForm::new(1.0,0).exp(), StandardForm::from(core::f64::consts::E));
+        let large = StandardForm::new(1.0,3).exp();
+        assert_eq!(*large.exponent(), 434);
+        assert!((large.mantissa() - 1.970_071_114_017_047).abs() < 1e-9);
+    }
+
+    #[test]
+    fn logarithms() {
+        assert_eq!(StandardForm::new(1.0,500).log10(), StandardForm::new(5.0,2));
+        assert_eq!(StandardForm::new(1.0,-500).log10(), StandardForm::new(-5.0,2));
+        assert!((f64::from(StandardForm::new(1.0,500).ln()) - 500.0 * LN_10).abs() < 1e-9);
+        assert!((f64::from(StandardForm::new(1.0,500).log2()) - 500.0 * LOG2_10).abs() < 1e-9);
+        assert_eq!(StandardForm::new(1.0,3).log2(), StandardForm::from(1000_f64.log2()));
+
+        assert!(StandardForm::new(-1.0,0).ln().mantissa().is_nan());
+        assert_eq!(StandardForm::new(0.0,0).log10(), StandardForm::new(f64::NEG_INFINITY,0));
+        assert_eq!(StandardForm::new(f64::INFINITY,0).log2(), StandardForm::new(f64::INFINITY,0));
+    }
+
+    #[test]
+    fn hypotenuse() {
+        assert_eq!(StandardForm::new(3.0,200).hypot(StandardForm::new(4.0,200)), StandardForm::new(5.0,200));
+        assert_eq!(StandardForm::new(3.0,-400).hypot(StandardForm::new(4.0,-400)), StandardForm::new(5.0,-400));
+        assert_eq!(StandardForm::new(1.0,500).hypot(StandardForm::new(1.0,0)), StandardForm::new(1.0,500));
+        assert_eq!(StandardForm::new(0.0,0).hypot(StandardForm::new(-1.0,-500)), StandardForm::new(1.0,-500));
+        assert_eq!(StandardForm::new(f64::NAN,0).hypot(StandardForm::new(f64::INFINITY,0)), StandardForm::new(f64::INFINITY,0));
+    }
+}
@@ -1,16 +1,278 @@
+//! Parsers for [`StandardForm`] built with [nom](https://docs.rs/nom).
+//!
+//! [`complete`] and [`streaming`] contain the same parsers for complete and streaming input respectively.
+//! They are generic over the input, which can be `&str` or `&[u8]`, and over the error type, so callers can
+//! use nom's `Error`, `VerboseError` or [`NomParsingError`] to get the contexts `"mantissa"` and `"exponent"`.
+//!
+//! Exponents must be integers that fit in an `i32`. Otherwise the parsers fail with `Err::Failure`, carrying an
+//! [`OverflowError`] reported through [`FromExternalError`] on overflow, instead of truncating the exponent.
+
+use core::ops::{Range,RangeFrom,RangeTo};
+
 use nom::{
-    branch::alt,
-    combinator::{map, opt},
-    sequence::{pair, preceded},
-    IResult, 
-    bytes::complete::tag, number::complete::double,
+    error::{ParseError,ContextError,FromExternalError},
+    AsBytes,AsChar,Compare,InputIter,InputLength,InputTake,InputTakeAtPosition,Offset,Slice,
+    IResult,
 };
 
 use crate::StandardForm;
 
+/// Input types accepted by the parsers in [`complete`] and [`streaming`], i.e. `&str` and `&[u8]`.
+pub trait ParserInput : Clone + Offset + AsBytes + InputLength + InputTake
+    + InputIter<Item : AsChar + Copy> + InputTakeAtPosition<Item : AsChar + Clone>
+    + Slice<Range<usize>> + Slice<RangeFrom<usize>> + Slice<RangeTo<usize>>
+    + Compare<&'static str> {}
+
+impl<I> ParserInput for I where I : Clone + Offset + AsBytes + InputLength + InputTake
+    + InputIter<Item : AsChar + Copy> + InputTakeAtPosition<Item : AsChar + Clone>
+    + Slice<Range<usize>> + Slice<RangeFrom<usize>> + Slice<RangeTo<usize>>
+    + Compare<&'static str> {}
+
+/// Error types accepted by the parsers in [`complete`] and [`streaming`].
+pub trait ParserError<I> : ParseError<I> + ContextError<I> + FromExternalError<I,OverflowError> {}
+
+impl<I,E> ParserError<I> for E where E : ParseError<I> + ContextError<I> + FromExternalError<I,OverflowError> {}
+
+/// Reported through [`FromExternalError`] when a number is well-formed but can't be represented as a [`StandardForm`].
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+pub enum OverflowError {
+    /// The written exponent doesn't fit in an `i32`, e.g. `1e3000000000`.
+    Exponent,
+    /// The exponent doesn't fit in an `i32` once the mantissa is normalised, e.g. `500e2147483646`.
+    Normalised
+}
+
+impl core::fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::Exponent => write!(f,"exponent does not fit in an i32"),
+            Self::Normalised => write!(f,"normalised exponent does not fit in an i32"),
+        }
+    }
+}
+
+/// An error type for the parsers in this module that keeps the [`OverflowError`] and every context, which nom's `Error` discards.
+#[cfg(feature = "std")]
+#[derive(Debug,Clone,PartialEq)]
+pub struct NomParsingError<I> {
+    /// The remaining input at the point of failure.
+    pub input : I,
+    /// The nom parser that failed.
+    pub kind : nom::error::ErrorKind,
+    /// Set when the number was well-formed but couldn't be represented.
+    pub overflow : Option<OverflowError>,
+    /// The contexts the failure happened in, innermost first.
+    pub contexts : Vec<(I,&'static str)>
+}
+
+#[cfg(feature = "std")]
+impl<I> ParseError<I> for NomParsingError<I> {
+    fn from_error_kind(input: I, kind: nom::error::ErrorKind) -> Self {
+        Self { input , kind , overflow : None , contexts : Vec::new() }
+    }
+
+    fn append(_: I, _: nom::error::ErrorKind, other: Self) -> Self {
+        other
+    }
+}
+
+#[cfg(feature = "std")]
+impl<I> ContextError<I> for NomParsingError<I> {
+    fn add_context(input: I, context: &'static str, mut other: Self) -> Self {
+        other.contexts.push((input,context));
+        other
+    }
+}
+
+#[cfg(feature = "std")]
+impl<I> FromExternalError<I,OverflowError> for NomParsingError<I> {
+    fn from_external_error(input: I, kind: nom::error::ErrorKind, error: OverflowError) -> Self {
+        Self { input , kind , overflow : Some(error) , contexts : Vec::new() }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<I> core::fmt::Display for NomParsingError<I> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self.overflow {
+            Some(overflow) => write!(f,"{overflow}")?,
+            None => write!(f,"{}",self.kind.description())?
+        }
+
+        for (_,context) in &self.contexts {
+            write!(f," in {context}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<I : core::fmt::Debug> std::error::Error for NomParsingError<I> {}
+
+/// Narrows a parsed exponent, failing without backtracking when it doesn't fit.
+fn to_exponent<I,E : ParserError<I>>(input : I,value : i64) -> Result<i32,nom::Err<E>> {
+    i32::try_from(value).map_err(|_| nom::Err::Failure(E::from_external_error(input,nom::error::ErrorKind::MapRes,OverflowError::Exponent)))
+}
+
+/// Folds ASCII digits with an optional sign into an `i64`, saturating instead of overflowing.
+fn integer(bytes : &[u8]) -> i64 {
+    let (negative,digits) = match bytes.split_first() {
+        Some((b'-',digits)) => (true,digits),
+        Some((b'+',digits)) => (false,digits),
+        _ => (false,bytes)
+    };
+
+    let magnitude = digits.iter().fold(0_i64,|value,digit| value.saturating_mul(10).saturating_add(i64::from(digit - b'0')));
+
+    match negative {
+        true => -magnitude,
+        false => magnitude
+    }
+}
+
+/// Builds the number from the recognised mantissa, checking that normalising it won't overflow the exponent.
+fn build(mantissa : &[u8],exponent : i32) -> Result<StandardForm,OverflowError> {
+    core::str::from_utf8(mantissa).ok()
+        .and_then(|mantissa| StandardForm::from_parts(mantissa,exponent))
+        .ok_or(OverflowError::Normalised)
+}
+
+macro_rules! parsers {
+    ($mode : ident, $doc : literal) => {
+        #[doc = $doc]
+        pub mod $mode {
+            use nom::{
+                branch::alt,
+                bytes::$mode::tag,
+                character::$mode::{char,digit0,digit1,space0},
+                combinator::{cut,map,map_res,not,opt,recognize,value},
+                error::context,
+                multi::fold_many1,
+                sequence::{pair,preceded,tuple},
+                IResult,
+            };
+
+            use crate::StandardForm;
+            use super::{ParserInput,ParserError,build,integer,to_exponent};
+
+            /// Recognises a decimal mantissa without an exponent, such as `-1.5`, `42` or `.5`.
+            pub fn mantissa<I : ParserInput,E : ParserError<I>>(input : I) -> IResult<I,I,E> {
+                context("mantissa",recognize(pair(
+                    opt(alt((char('+'),char('-')))),
+                    alt((
+                        recognize(pair(digit1,opt(pair(char('.'),digit0)))),
+                        recognize(pair(char('.'),digit1)),
+                    ))
+                )))(input)
+            }
+
+            /// Parses a signed integer exponent such as `-12` or `+5`.
+            ///
+            /// Fractional exponents like `3.7` and exponents that don't fit in an `i32` are reported as `Err::Failure`,
+            /// the latter with [`OverflowError::Exponent`](super::OverflowError::Exponent), so they aren't silently truncated.
+            pub fn integer_exponent<I : ParserInput,E : ParserError<I>>(input : I) -> IResult<I,i32,E> {
+                context("exponent",|input : I| {
+                    let (rest,digits) = recognize(pair(opt(alt((char('+'),char('-')))),digit1))(input.clone())?;
+                    let (rest,_) = cut(not(pair(char('.'),digit1)))(rest)?;
+                    Ok((rest,to_exponent(input,integer(digits.as_bytes()))?))
+                })(input)
+            }
+
+            /// Parses a superscript exponent such as `⁵` or `⁻¹²`.
+            pub fn superscript_exponent<I : ParserInput,E : ParserError<I>>(input : I) -> IResult<I,i32,E> {
+                let digit = alt((
+                    value(0,tag("⁰")),value(1,tag("¹")),value(2,tag("²")),value(3,tag("³")),value(4,tag("⁴")),
+                    value(5,tag("⁵")),value(6,tag("⁶")),value(7,tag("⁷")),value(8,tag("⁸")),value(9,tag("⁹")),
+                ));
+
+                let mut superscript = pair(
+                    opt(alt((value(-1,tag("⁻")),value(1,tag("⁺"))))),
+                    fold_many1(digit,|| 0_i64,|value : i64,digit : i64| value.saturating_mul(10).saturating_add(digit))
+                );
+
+                context("exponent",move |input : I| {
+                    let (rest,(sign,magnitude)) = superscript(input.clone())?;
+                    Ok((rest,to_exponent(input,sign.unwrap_or(1) * magnitude)?))
+                })(input)
+            }
+
+            /// Recognises a multiplication sign: `*`, `x`, `X`, `×`, `·` or `⋅`.
+            pub fn multiplier<I : ParserInput,E : ParserError<I>>(input : I) -> IResult<I,I,E> {
+                alt((tag("*"),tag("x"),tag("X"),tag("×"),tag("·"),tag("⋅")))(input)
+            }
+
+            /// Parses an `e` exponent such as `e5`, `E-3` or `e+05`.
+            pub fn e_exponent<I : ParserInput,E : ParserError<I>>(input : I) -> IResult<I,i32,E> {
+                preceded(alt((char('e'),char('E'))),integer_exponent)(input)
+            }
+
+            /// Parses a power of ten such as `*10^5`, ` × 10^-3` or `x10**5`.
+            pub fn power_exponent<I : ParserInput,E : ParserError<I>>(input : I) -> IResult<I,i32,E> {
+                preceded(
+                    tuple((space0,multiplier,space0,tag("10"),alt((tag("^"),tag("**"))))),
+                    integer_exponent
+                )(input)
+            }
+
+            /// Parses a power of ten with a superscript exponent such as `·10⁵` or ` × 10⁻³`.
+            pub fn unicode_power_exponent<I : ParserInput,E : ParserError<I>>(input : I) -> IResult<I,i32,E> {
+                preceded(tuple((space0,multiplier,space0,tag("10"))),superscript_exponent)(input)
+            }
+
+            /// Parses any exponent accepted by [`e_exponent`], [`power_exponent`] or [`unicode_power_exponent`].
+            pub fn exponent<I : ParserInput,E : ParserError<I>>(input : I) -> IResult<I,i32,E> {
+                alt((e_exponent,power_exponent,unicode_power_exponent))(input)
+            }
+
+            /// Parses an SI prefix such as `k`, `M` or `µ` into its power of ten.
+            pub fn si_prefix<I : ParserInput,E : ParserError<I>>(input : I) -> IResult<I,i32,E> {
+                alt((
+                    alt((
+                        value(30,tag("Q")),value(27,tag("R")),value(24,tag("Y")),value(21,tag("Z")),
+                        value(18,tag("E")),value(15,tag("P")),value(12,tag("T")),value(9,tag("G")),
+                        value(6,tag("M")),value(3,tag("k")),value(2,tag("h")),value(1,tag("da")),
+                    )),
+                    alt((
+                        value(-1,tag("d")),value(-2,tag("c")),value(-3,tag("m")),
+                        value(-6,tag("µ")),value(-6,tag("μ")),value(-6,tag("u")),
+                        value(-9,tag("n")),value(-12,tag("p")),value(-15,tag("f")),value(-18,tag("a")),
+                        value(-21,tag("z")),value(-24,tag("y")),value(-27,tag("r")),value(-30,tag("q")),
+                    ))
+                ))(input)
+            }
+
+            fn standard_form<I : ParserInput>((mantissa,exponent) : (I,i32)) -> Result<StandardForm,super::OverflowError> {
+                build(mantissa.as_bytes(),exponent)
+            }
+
+            /// Parses a number with a mandatory exponent, written as `1.2e5`, `1.2E+05`, `1.2*10^5`, `1.2 × 10^5` or `1.2·10⁵`.
+            pub fn standard_form_with_required_exponent<I : ParserInput,E : ParserError<I>>(input : I) -> IResult<I,StandardForm,E> {
+                map_res(pair(mantissa,exponent),standard_form)(input)
+            }
+
+            /// Parses a number like [`standard_form_with_required_exponent`], but also accepts a plain mantissa such as `42`.
+            pub fn standard_form_with_optional_exponent<I : ParserInput,E : ParserError<I>>(input : I) -> IResult<I,StandardForm,E> {
+                map_res(
+                    pair(mantissa,map(opt(exponent),|exponent| exponent.unwrap_or(0))),
+                    standard_form
+                )(input)
+            }
+
+            /// Parses a number followed by an SI prefix, such as `1.5k` or `2.2 µ`.
+            pub fn standard_form_with_si_prefix<I : ParserInput,E : ParserError<I>>(input : I) -> IResult<I,StandardForm,E> {
+                map_res(pair(mantissa,preceded(space0,si_prefix)),standard_form)(input)
+            }
+        }
+    };
+}
+
+parsers!(complete,"Parsers for input that is known to be complete, so the end of input terminates a number.");
+parsers!(streaming,"Parsers for partial input, which return `Err::Incomplete` when a number may continue past the end of the input.");
+
 /// Parses a string in standard form (scientific notation) and returns a `StandardForm` struct.
 ///
-/// The standard form number can be written as , "mantissaeexponent", 
+/// The standard form number can be written as , "mantissaeexponent",
 /// "mantissaEexponent", or "mantissa*10^exponent". It supports both positive and negative exponents.
 ///
 /// # Arguments
@@ -20,11 +282,8 @@ use crate::StandardForm;
 /// # Returns
 ///
 /// Returns a `Result` containing the parsed `StandardForm` struct if successful, or a parsing error.
-pub fn parse_standard_form_with_required_exponent(input: &str) -> IResult<&str, StandardForm> {    
-    map(
-        pair(double,parse_exponent),
-        |(mantissa , exponent)| StandardForm::new(mantissa,exponent) 
-    )(input)
+pub fn parse_standard_form_with_required_exponent(input: &str) -> IResult<&str, StandardForm> {
+    complete::standard_form_with_required_exponent(input)
 }
 
 /// Parses a string in standard form (scientific notation) and returns a `StandardForm` struct.
@@ -39,23 +298,13 @@ pub fn parse_standard_form_with_required_exponent(input: &str) -> IResult<&str,
 ///
 /// Returns a `Result` containing the parsed `StandardForm` struct if successful, or a parsing error.
 pub fn parse_standard_form_with_optional_exponent(input: &str) -> IResult<&str, StandardForm> {
-    map(
-        pair(double,opt(parse_exponent)),
-        |(mantissa , exponent)| StandardForm::new(mantissa,exponent.unwrap_or(0)) 
-    )(input)
-}
-
-fn parse_exponent(input: &str) -> IResult<&str,i8> {
-    preceded(
-        alt((tag("e"), tag("E"), tag("*10^"))),
-        map(double, |exp: f64| exp as i8)
-    )(input)
+    complete::standard_form_with_optional_exponent(input)
 }
 
 #[cfg(test)]
 mod parse_standardform_tests {
     use super::*;
-    
+
     #[test]
     fn test_parse_standard_form_positive_exponent() {
         let input = "1.23e3"; // Example input: 1230.0
@@ -67,7 +316,7 @@ mod parse_standardform_tests {
             Err(e) => panic!("Parsing error: {:?}", e),
         }
     }
-    
+
     #[test]
     fn test_parse_standard_form_negative_exponent() {
         let input = "2.5*10^-2"; // Example input: 0.025
@@ -79,7 +328,7 @@ mod parse_standardform_tests {
             Err(e) => panic!("Parsing error: {:?}", e),
         }
     }
-    
+
     #[test]
     fn test_parse_standard_form_no_exponent() {
         let input = "42"; // No exponent, should be treated as 42.0 * 10^0
@@ -91,7 +340,7 @@ mod parse_standardform_tests {
             Err(e) => panic!("Parsing error: {:?}", e),
         }
     }
-    
+
     #[test]
     fn test_parse_standard_form_star_exponent() {
         let input = "5*10^4"; // Example input: 50000.0
@@ -103,7 +352,7 @@ mod parse_standardform_tests {
             Err(e) => panic!("Parsing error: {:?}", e),
         }
     }
-    
+
     #[test]
     fn test_parse_standard_form_invalid_input() {
         let input = "invalid"; // Invalid input, should result in an error
@@ -112,4 +361,68 @@ mod parse_standardform_tests {
             Err(_) => {} // Expected result
         }
     }
+
+    #[test]
+    fn test_parse_standard_form_required_exponent() {
+        assert!(parse_standard_form_with_required_exponent("1.23e3").is_ok_and(|(rest, _)| rest.is_empty()));
+        assert!(parse_standard_form_with_required_exponent("42").is_err());
+    }
+
+    #[test]
+    fn test_parse_standard_form_unicode() {
+        let (rest, standard_form) = parse_standard_form_with_required_exponent("1.2 × 10⁻⁵").unwrap();
+        assert!(rest.is_empty());
+        assert_eq!(standard_form, StandardForm::new(1.2, -5));
+    }
+
+    #[test]
+    fn test_parse_standard_form_bytes() {
+        let input : &[u8] = b"1.2*10**5 rest";
+        let (rest, standard_form) = complete::standard_form_with_required_exponent::<_, nom::error::Error<_>>(input).unwrap();
+        assert_eq!(rest, b" rest");
+        assert_eq!(standard_form, StandardForm::new(1.2, 5));
+    }
+
+    #[test]
+    fn test_parse_standard_form_streaming() {
+        let result = streaming::standard_form_with_optional_exponent::<_, nom::error::Error<_>>("1.5e");
+        assert!(matches!(result, Err(nom::Err::Incomplete(_))));
+
+        let (rest, standard_form) = streaming::standard_form_with_optional_exponent::<_, nom::error::Error<_>>("1.5e3;").unwrap();
+        assert_eq!(rest, ";");
+        assert_eq!(standard_form, StandardForm::new(1.5, 3));
+    }
+
+    #[test]
+    fn test_parse_standard_form_strict_exponent() {
+        assert!(parse_standard_form_with_required_exponent("1*10^3.7").is_err());
+        assert!(matches!(parse_standard_form_with_optional_exponent("1e3.7"), Err(nom::Err::Failure(_))));
+        assert_eq!(parse_standard_form_with_optional_exponent("1e3.").map(|(rest, _)| rest), Ok("."));
+
+        let error = match complete::standard_form_with_required_exponent::<_, NomParsingError<_>>("1e3000000000") {
+            Err(nom::Err::Failure(error)) => error,
+            other => panic!("Expected overflow error but got {other:?}")
+        };
+        assert_eq!(error.overflow, Some(OverflowError::Exponent));
+        assert_eq!(error.contexts.iter().map(|(_, context)| *context).collect::<Vec<_>>(), ["exponent"]);
+
+        let result = complete::standard_form_with_required_exponent::<_, NomParsingError<_>>("500e2147483646");
+        assert!(matches!(result, Err(nom::Err::Error(NomParsingError { overflow : Some(OverflowError::Normalised), .. }))));
+
+        // the mantissa rounds up to 10, carrying into the exponent
+        let result = complete::standard_form_with_optional_exponent::<_, NomParsingError<_>>("9.99999999999999999999e2147483647");
+        assert!(matches!(result, Err(nom::Err::Error(NomParsingError { overflow : Some(OverflowError::Normalised), .. }))));
+        assert!(parse_standard_form_with_optional_exponent("9.99999999999999999999e2147483647").is_err());
+        assert_eq!(parse_standard_form_with_optional_exponent("9.99999999999999999999e2147483646").map(|(_, standard_form)| standard_form), Ok(StandardForm::new(1.0, 2147483647)));
+    }
+
+    #[test]
+    fn test_parse_standard_form_si_prefix() {
+        let (rest, standard_form) = complete::standard_form_with_si_prefix::<_, nom::error::Error<_>>("1.5 km").unwrap();
+        assert_eq!(rest, "m");
+        assert_eq!(standard_form, StandardForm::new(1.5, 3));
+
+        let (_, standard_form) = complete::standard_form_with_si_prefix::<_, nom::error::Error<_>>("2.2µ").unwrap();
+        assert_eq!(standard_form, StandardForm::new(2.2, -6));
+    }
 }
@@ -1,11 +1,20 @@
 use num_traits::{
     identities::{One,Zero},
-    cast::FromPrimitive,
-    ToPrimitive,
-    Pow
+    cast::{FromPrimitive,NumCast},
+    ops::checked::{CheckedAdd,CheckedSub,CheckedMul,CheckedDiv,CheckedRem,CheckedNeg},
+    ops::inv::Inv,
+    ops::mul_add::{MulAdd,MulAddAssign},
+    float::FloatConst,
+    Bounded,
+    Euclid,
+    ToPrimitive
 };
 
+#[cfg(feature = "std")]
+use num_traits::Pow;
+
 use crate::StandardForm;
+use crate::sf::pow10;
 
 /// Represents a standard form number with zero mantissa and zero exponent.
 pub static ZERO: StandardForm = StandardForm::new_unchecked(0.0, 0);
@@ -56,7 +65,7 @@ impl num_traits::Signed for StandardForm {
     fn abs_sub(&self, other: &Self) -> Self {
         match *self <= *other {
             true => Self::zero(),
-            false => self.clone() - other.clone()
+            false => *self - *other
         }
     }
     
@@ -84,36 +93,570 @@ impl FromPrimitive for StandardForm {
     // Required methods
     #[must_use]
     fn from_i64(n: i64) -> Option<Self> {
-        Some(Self::new(n as f64,0))
+        Some(n.into())
     }
     #[must_use]
     fn from_u64(n: u64) -> Option<Self> {
-        Some(Self::new(n as f64,0))
+        Some(n.into())
+    }
+
+    fn from_i128(n: i128) -> Option<Self> {
+        Some(n.into())
+    }
+
+    fn from_u128(n: u128) -> Option<Self> {
+        Some(n.into())
+    }
+
+    /// Always succeeds, as every `f64` fits in a `StandardForm`.
+    fn from_f64(n: f64) -> Option<Self> {
+        Self::checked_new(n,0)
     }
 }
 
+/// Integer conversions are exact and return `None` exactly when the matching `TryFrom` fails, i.e. for numbers that are
+/// too large, negative numbers converted into unsigned types and numbers with a fractional part.
 impl ToPrimitive for StandardForm {
-    // Required methods
     #[must_use]
     fn to_i64(&self) -> Option<i64> {
-        let x : f64 = self.clone().into();
-        Some(x as i64)
+        i64::try_from(*self).ok()
     }
     
     #[must_use]
     fn to_u64(&self) -> Option<u64> {
-        let x : f64 = self.clone().into();
-        Some(x as u64)
+        u64::try_from(*self).ok()
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        i128::try_from(*self).ok()
+    }
+
+    fn to_u128(&self) -> Option<u128> {
+        u128::try_from(*self).ok()
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        Some((*self).into())
+    }
+}
+
+impl NumCast for StandardForm {
+    /// Converts through `f64`, so this only fails if `n` can't be converted into an `f64`.
+    fn from<T : ToPrimitive>(n: T) -> Option<Self> {
+        n.to_f64().map(<Self as From<f64>>::from)
+    }
+}
+
+impl Bounded for StandardForm {
+    /// Returns `-9.999…e2147483647`.
+    fn min_value() -> Self {
+        -Self::max_value()
+    }
+
+    /// Returns `9.999…e2147483647`, i.e. the largest mantissa below 10 with the largest exponent.
+    fn max_value() -> Self {
+        StandardForm::new_unchecked(f64::from_bits(10.0_f64.to_bits() - 1),i32::MAX)
     }
 }
 
+impl Inv for StandardForm {
+    type Output = Self;
+
+    fn inv(self) -> Self {
+        Self::one() / self
+    }
+}
+
+impl MulAdd for StandardForm {
+    type Output = Self;
+
+    /// Computes `self * a + b` with the operators of `StandardForm`, so the product keeps its exponent exactly.
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        self * a + b
+    }
+}
+
+impl MulAddAssign for StandardForm {
+    fn mul_add_assign(&mut self, a: Self, b: Self) {
+        *self = *self * a + b;
+    }
+}
+
+impl Euclid for StandardForm {
+    fn div_euclid(&self, v: &Self) -> Self {
+        StandardForm::div_euclid(*self,*v)
+    }
+
+    fn rem_euclid(&self, v: &Self) -> Self {
+        StandardForm::rem_euclid(*self,*v)
+    }
+}
+
+/// Returns `None` if the exponent of the result doesn't fit in an `i32`, mirroring how `Add` aligns the exponents.
+impl CheckedAdd for StandardForm {
+    fn checked_add(&self, v: &Self) -> Option<Self> {
+        let max_power = *self.exponent().max(v.exponent());
+        let sum = self.mantissa() * pow10(self.exponent().saturating_sub(max_power)) + v.mantissa() * pow10(v.exponent().saturating_sub(max_power));
+        Self::checked_new(sum,max_power)
+    }
+}
+
+/// Returns `None` if the exponent of the result doesn't fit in an `i32`.
+impl CheckedSub for StandardForm {
+    fn checked_sub(&self, v: &Self) -> Option<Self> {
+        let max_power = *self.exponent().max(v.exponent());
+        let difference = self.mantissa() * pow10(self.exponent().saturating_sub(max_power)) - v.mantissa() * pow10(v.exponent().saturating_sub(max_power));
+        Self::checked_new(difference,max_power)
+    }
+}
+
+/// Returns `None` if the exponent of the result doesn't fit in an `i32`, e.g. for `1e2000000000 * 1e2000000000`.
+impl CheckedMul for StandardForm {
+    fn checked_mul(&self, v: &Self) -> Option<Self> {
+        i32::checked_add(*self.exponent(),*v.exponent()).and_then(|exponent| Self::checked_new(self.mantissa() * v.mantissa(),exponent))
+    }
+}
+
+/// Returns `None` when dividing by zero or if the exponent of the result doesn't fit in an `i32`.
+impl CheckedDiv for StandardForm {
+    fn checked_div(&self, v: &Self) -> Option<Self> {
+        match v.is_zero() {
+            true => None,
+            false => i32::checked_sub(*self.exponent(),*v.exponent()).and_then(|exponent| Self::checked_new(self.mantissa() / v.mantissa(),exponent))
+        }
+    }
+}
+
+/// Returns `None` when dividing by zero.
+impl CheckedRem for StandardForm {
+    fn checked_rem(&self, v: &Self) -> Option<Self> {
+        match v.is_zero() {
+            true => None,
+            false => Some(*self % *v)
+        }
+    }
+}
+
+/// Always succeeds, as every number has a negation.
+impl CheckedNeg for StandardForm {
+    fn checked_neg(&self) -> Option<Self> {
+        Some(-*self)
+    }
+}
+
+macro_rules! constants {
+    ($($name : ident),*) => {
+        impl FloatConst for StandardForm {
+            $(
+                fn $name() -> Self {
+                    core::f64::consts::$name.into()
+                }
+            )*
+        }
+    };
+}
+
+constants!(E,FRAC_1_PI,FRAC_1_SQRT_2,FRAC_2_PI,FRAC_2_SQRT_PI,FRAC_PI_2,FRAC_PI_3,FRAC_PI_4,FRAC_PI_6,FRAC_PI_8,LN_10,LN_2,LOG10_E,LOG2_E,PI,SQRT_2,TAU,LOG10_2,LOG2_10);
+
+#[cfg(feature = "std")]
+impl StandardForm {
+    /// Applies an `f64` function to the number.
+    fn apply(self,function : impl FnOnce(f64) -> f64) -> Self {
+        function(self.into()).into()
+    }
+
+    /// Applies an `f64` function that only changes the fractional digits, returning numbers that are already integers as they are.
+    fn apply_integral(self,function : impl FnOnce(f64) -> f64) -> Self {
+        // with at most 17 significant digits, larger numbers are already integers
+        if *self.exponent() >= 17 || !self.mantissa().is_finite() {
+            return self;
+        }
+
+        // every number below 0.01 rounds like 0.01 does, which keeps tiny numbers from underflowing the f64
+        function(self.mantissa() * pow10((*self.exponent()).max(-2))).into()
+    }
+}
+
+/// Lets `StandardForm` be used by algorithms written for any `T : Float`.
+///
+/// Classification, rounding, signs, `min`, `max`, `recip` and `mul_add` work on the mantissa and exponent directly.
+/// Powers, roots, exponentials, logarithms, `hypot` and the trigonometric functions use the functions of
+/// `StandardForm`, so they keep the full range of the exponent. The other functions are evaluated in `f64`. As every
+/// number is canonical, `neg_zero` returns zero.
+///
+/// ```
+/// use num_traits::Float;
+/// use standardform::StandardForm;
+///
+/// fn norm<T : Float>(x : T,y : T) -> T {
+///     (x * x + y * y).sqrt()
+/// }
+///
+/// assert_eq!(norm(StandardForm::new(3.0,10),StandardForm::new(4.0,10)), StandardForm::new(5.0,10));
+/// ```
+#[cfg(feature = "std")]
+impl num_traits::Float for StandardForm {
+    fn nan() -> Self {
+        Self::new(f64::NAN,0)
+    }
+
+    fn infinity() -> Self {
+        Self::new(f64::INFINITY,0)
+    }
+
+    fn neg_infinity() -> Self {
+        Self::new(f64::NEG_INFINITY,0)
+    }
+
+    fn neg_zero() -> Self {
+        Self::zero()
+    }
+
+    fn min_value() -> Self {
+        <Self as Bounded>::min_value()
+    }
+
+    fn min_positive_value() -> Self {
+        Self::new_unchecked(1.0,i32::MIN)
+    }
+
+    fn epsilon() -> Self {
+        f64::EPSILON.into()
+    }
+
+    fn max_value() -> Self {
+        <Self as Bounded>::max_value()
+    }
+
+    fn is_nan(self) -> bool {
+        self.mantissa().is_nan()
+    }
+
+    fn is_infinite(self) -> bool {
+        self.mantissa().is_infinite()
+    }
+
+    fn is_finite(self) -> bool {
+        self.mantissa().is_finite()
+    }
+
+    /// Every finite non-zero number is normal, as the mantissa always lies in `[1, 10)`.
+    fn is_normal(self) -> bool {
+        self.mantissa().is_normal()
+    }
+
+    fn classify(self) -> core::num::FpCategory {
+        self.mantissa().classify()
+    }
+
+    fn floor(self) -> Self {
+        self.apply_integral(f64::floor)
+    }
+
+    fn ceil(self) -> Self {
+        self.apply_integral(f64::ceil)
+    }
+
+    fn round(self) -> Self {
+        self.apply_integral(f64::round)
+    }
+
+    fn trunc(self) -> Self {
+        self.apply_integral(f64::trunc)
+    }
+
+    fn fract(self) -> Self {
+        match self.exponent() {
+            _ if !self.mantissa().is_finite() => self.apply(f64::fract),
+            17.. => Self::zero(),
+            // numbers below one are their own fractional part
+            ..0 => self,
+            _ => self.apply(f64::fract)
+        }
+    }
+
+    fn abs(self) -> Self {
+        Self::new_unchecked(self.mantissa().abs(),*self.exponent())
+    }
+
+    fn signum(self) -> Self {
+        Self::new(self.mantissa().signum(),0)
+    }
+
+    fn is_sign_positive(self) -> bool {
+        self.mantissa().is_sign_positive()
+    }
+
+    fn is_sign_negative(self) -> bool {
+        self.mantissa().is_sign_negative()
+    }
+
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        MulAdd::mul_add(self,a,b)
+    }
+
+    fn recip(self) -> Self {
+        self.inv()
+    }
+
+    fn powi(self, n: i32) -> Self {
+        StandardForm::powi(self,n)
+    }
+
+    fn powf(self, n: Self) -> Self {
+        StandardForm::pow(self,n)
+    }
+
+    fn sqrt(self) -> Self {
+        StandardForm::sqrt(self)
+    }
+
+    fn exp(self) -> Self {
+        StandardForm::exp(self)
+    }
+
+    fn exp2(self) -> Self {
+        StandardForm::pow(Self::new(2.0,0),self)
+    }
+
+    fn ln(self) -> Self {
+        StandardForm::ln(self)
+    }
+
+    fn log(self, base: Self) -> Self {
+        StandardForm::ln(self) / StandardForm::ln(base)
+    }
+
+    fn log2(self) -> Self {
+        StandardForm::log2(self)
+    }
+
+    fn log10(self) -> Self {
+        StandardForm::log10(self)
+    }
+
+    fn to_degrees(self) -> Self {
+        Self::saturating_new(self.mantissa().to_degrees(),(*self.exponent()).into())
+    }
+
+    fn to_radians(self) -> Self {
+        Self::saturating_new(self.mantissa().to_radians(),(*self.exponent()).into())
+    }
+
+    /// Returns the larger number, ignoring NaN like `f64::max`.
+    fn max(self, other: Self) -> Self {
+        match (self.mantissa().is_nan(),other.mantissa().is_nan()) {
+            (true,_) => other,
+            (false,true) => self,
+            (false,false) => Ord::max(self,other)
+        }
+    }
+
+    /// Returns the smaller number, ignoring NaN like `f64::min`.
+    fn min(self, other: Self) -> Self {
+        match (self.mantissa().is_nan(),other.mantissa().is_nan()) {
+            (true,_) => other,
+            (false,true) => self,
+            (false,false) => Ord::min(self,other)
+        }
+    }
+
+    fn abs_sub(self, other: Self) -> Self {
+        num_traits::Signed::abs_sub(&self,&other)
+    }
+
+    fn cbrt(self) -> Self {
+        StandardForm::cbrt(self)
+    }
+
+    fn hypot(self, other: Self) -> Self {
+        StandardForm::hypot(self,other)
+    }
+
+    fn sin(self) -> Self {
+        StandardForm::sin(self)
+    }
+
+    fn cos(self) -> Self {
+        StandardForm::cos(self)
+    }
+
+    fn tan(self) -> Self {
+        StandardForm::tan(self)
+    }
+
+    /// Returns NaN outside `[-1, 1]`, like `f64::asin`.
+    fn asin(self) -> Self {
+        StandardForm::asin(self).unwrap_or_else(|_| Self::nan())
+    }
+
+    /// Returns NaN outside `[-1, 1]`, like `f64::acos`.
+    fn acos(self) -> Self {
+        StandardForm::acos(self).unwrap_or_else(|_| Self::nan())
+    }
+
+    fn atan(self) -> Self {
+        StandardForm::atan(self)
+    }
+
+    fn atan2(self, other: Self) -> Self {
+        StandardForm::atan2(self,other)
+    }
+
+    fn sin_cos(self) -> (Self, Self) {
+        StandardForm::sin_cos(self)
+    }
+
+    fn exp_m1(self) -> Self {
+        self.apply(f64::exp_m1)
+    }
+
+    fn ln_1p(self) -> Self {
+        self.apply(f64::ln_1p)
+    }
+
+    fn sinh(self) -> Self {
+        StandardForm::sinh(self)
+    }
+
+    fn cosh(self) -> Self {
+        StandardForm::cosh(self)
+    }
+
+    fn tanh(self) -> Self {
+        StandardForm::tanh(self)
+    }
+
+    fn asinh(self) -> Self {
+        StandardForm::asinh(self)
+    }
+
+    fn acosh(self) -> Self {
+        StandardForm::acosh(self)
+    }
+
+    /// Returns NaN outside `[-1, 1]`, like `f64::atanh`.
+    fn atanh(self) -> Self {
+        StandardForm::atanh(self).unwrap_or_else(|_| Self::nan())
+    }
+
+    /// Decodes the value as an `f64`.
+    fn integer_decode(self) -> (u64, i16, i8) {
+        num_traits::Float::integer_decode(self.into_f64())
+    }
+}
+
+#[cfg(feature = "std")]
 impl Pow<Self> for StandardForm {
-    type Output = f64;
+    type Output = Self;
 
+    /// Raises the number to a power with [`StandardForm::pow`].
     #[must_use]
     fn pow(self, other: Self) -> Self::Output {
-        let x : f64 = self.into();
-        let y : f64 = other.into();
-        x.powf(y)
+        StandardForm::pow(self,other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use num_traits::Float;
+
+    fn newton_sqrt<T : Float>(value : T) -> T {
+        let two = T::one() + T::one();
+        let mut guess = value;
+
+        for _ in 0..200 {
+            guess = (guess + value / guess) / two;
+        }
+
+        guess
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn generic_float() {
+        assert_eq!(newton_sqrt(StandardForm::new(1.0,100)), StandardForm::new(1.0,50));
+        assert_eq!(Float::sqrt(StandardForm::new(4.0,-120)), StandardForm::new(2.0,-60));
+        assert_eq!(Float::powi(StandardForm::new(1.0,200),2), StandardForm::new(1.0,400));
+        assert_eq!(Float::powi(StandardForm::new(1.0,2_000_000_000),2), StandardForm::infinity());
+        assert_eq!(Float::powi(StandardForm::new(1.0,-2_000_000_000),2), StandardForm::zero());
+        assert_eq!(Float::powf(StandardForm::new(1.0,600),StandardForm::new(5.0,-1)), StandardForm::new(1.0,300));
+        assert_eq!(Float::log10(StandardForm::new(1.0,-400)), StandardForm::new(-4.0,2));
+        assert_eq!(Float::exp2(StandardForm::new(1.0,1)), StandardForm::new(1.024,3));
+        assert_eq!(Float::max(StandardForm::nan(),StandardForm::one()), StandardForm::one());
+        assert_eq!(Float::floor(StandardForm::new(-2.5,0)), StandardForm::new(-3.0,0));
+        assert_eq!(Float::fract(StandardForm::new(1.25,1)), StandardForm::new(5.0,-1));
+        assert_eq!(Float::fract(StandardForm::new(1.0,-500)), StandardForm::new(1.0,-500));
+        assert_eq!(Float::ceil(StandardForm::new(1.0,-500)), StandardForm::one());
+        assert_eq!(Float::round(StandardForm::new(-6.0,-1)), StandardForm::new(-1.0,0));
+        assert_eq!(Float::to_degrees(StandardForm::new(core::f64::consts::PI,400)), StandardForm::new(1.8,402));
+        assert_eq!(Float::to_degrees(StandardForm::new(1.0,i32::MAX)), StandardForm::infinity());
+        assert_eq!(Float::to_radians(StandardForm::new(1.0,i32::MIN)), StandardForm::zero());
+        assert_eq!(Float::round(StandardForm::new(1.5,100)), StandardForm::new(1.5,100));
+        assert_eq!(Float::mul_add(StandardForm::new(2.0,100),StandardForm::new(3.0,-100),StandardForm::one()), StandardForm::new(7.0,0));
+        assert!(Float::is_normal(StandardForm::min_positive_value()));
+        assert!(<StandardForm as Bounded>::min_value() < StandardForm::new(-9.99,i32::MAX));
+        assert!(<StandardForm as Bounded>::max_value() > StandardForm::new(9.99,i32::MAX));
+
+        let one = StandardForm::one();
+        assert_ne!(one + <StandardForm as Float>::epsilon(), one);
+        assert_eq!(StandardForm::new(1.0000001,0) - one, StandardForm::new(1.0,-7));
+        assert_eq!(StandardForm::new(1.2345678901,0) * one, StandardForm::new(1.2345678901,0));
+        assert_eq!(<StandardForm as Float>::max_value() * StandardForm::new(1.0,1), StandardForm::infinity());
+    }
+
+    #[test]
+    fn casts() {
+        assert_eq!(<StandardForm as NumCast>::from(1.5_f32), Some(StandardForm::new(1.5,0)));
+        assert_eq!(<StandardForm as NumCast>::from(1e200), Some(StandardForm::new(1.0,200)));
+        assert_eq!(StandardForm::from_f64(2.5e-3), Some(StandardForm::new(2.5,-3)));
+        assert_eq!(StandardForm::new(1.5,0).to_f64(), Some(1.5));
+        assert_eq!(StandardForm::new(1.0,100).to_u64(), None);
+        assert_eq!(StandardForm::new(-1.0,0).to_u64(), None);
+        assert_eq!(StandardForm::new(1.5,0).to_i64(), None);
+        assert_eq!(StandardForm::new(-3.2,4).to_i32(), Some(-32_000));
+        assert_eq!(StandardForm::new(1.0,30).to_u128(), Some(10_u128.pow(30)));
+        assert_eq!(<f32 as NumCast>::from(StandardForm::new(2.5,-1)), Some(0.25));
+    }
+
+    #[test]
+    fn checked() {
+        let large = StandardForm::new(5.0,i32::MAX);
+
+        assert_eq!(large.checked_add(&large), None);
+        assert_eq!(large.checked_sub(&-large), None);
+        assert_eq!(large.checked_mul(&StandardForm::new(1.0,1)), None);
+        assert_eq!(StandardForm::new(1.0,-2_000_000_000).checked_mul(&StandardForm::new(1.0,-2_000_000_000)), None);
+        assert_eq!(StandardForm::new(1.0,-2_000_000_000).checked_div(&StandardForm::new(1.0,2_000_000_000)), None);
+        assert_eq!(StandardForm::one().checked_div(&StandardForm::zero()), None);
+        assert_eq!(StandardForm::one().checked_rem(&StandardForm::zero()), None);
+
+        assert_eq!(large.checked_add(&StandardForm::one()), Some(large));
+        assert_eq!(StandardForm::new(3.0,5).checked_mul(&StandardForm::new(4.0,5)), Some(StandardForm::new(1.2,11)));
+        assert_eq!(StandardForm::new(3.0,5).checked_div(&StandardForm::new(4.0,5)), Some(StandardForm::new(7.5,-1)));
+        assert_eq!(StandardForm::new(3.0,5).checked_sub(&StandardForm::new(4.0,5)), Some(StandardForm::new(-1.0,5)));
+        assert_eq!(StandardForm::new(1.0000001,0).checked_sub(&StandardForm::one()), Some(StandardForm::new(1.0000001 - 1.0,0)));
+        assert_eq!(StandardForm::new(1.2345678901,0).checked_mul(&StandardForm::one()), Some(StandardForm::new(1.2345678901,0)));
+        assert_eq!(StandardForm::new(7.0,0).checked_rem(&StandardForm::new(4.0,0)), Some(StandardForm::new(3.0,0)));
+        assert_eq!(large.checked_neg(), Some(StandardForm::new(-5.0,i32::MAX)));
+        assert_eq!(StandardForm::new(1.0,200).checked_mul(&StandardForm::new(1.0,200)), Some(StandardForm::new(1.0,400)));
+    }
+
+    #[test]
+    fn operations() {
+        assert_eq!(StandardForm::new(4.0,3).inv(), StandardForm::new(2.5,-4));
+        assert_eq!(Euclid::div_euclid(&StandardForm::new(-7.0,0),&StandardForm::new(2.0,0)), StandardForm::new(-4.0,0));
+        assert_eq!(Euclid::rem_euclid(&StandardForm::new(-7.0,0),&StandardForm::new(2.0,0)), StandardForm::one());
+
+        let mut value = StandardForm::new(2.0,0);
+        value.mul_add_assign(StandardForm::new(3.0,0),StandardForm::new(4.0,0));
+        assert_eq!(value, StandardForm::new(1.0,1));
+
+        assert_eq!(StandardForm::PI(), StandardForm::new(core::f64::consts::PI,0));
+        assert_eq!(StandardForm::TAU(), StandardForm::new(core::f64::consts::TAU,0));
+
+        assert_eq!(Pow::pow(StandardForm::new(2.0,100),5_u8), StandardForm::new(3.2,501));
+        assert_eq!(Pow::pow(StandardForm::new(1.0,2),StandardForm::new(2.5,2)), StandardForm::new(1.0,500));
+    }
+}
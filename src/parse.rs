@@ -0,0 +1,559 @@
+use core::ops::Range;
+
+use crate::{StandardForm,Notation,ParsingStandardFormError,ParsingStandardFormDiagnostic};
+use crate::error::floor_char_boundary;
+
+/// Characters accepted between the mantissa and the `10` of a power, as in `1.2 × 10^5`.
+const MULTIPLIERS : [char;6] = ['*','x','X','×','·','⋅'];
+
+/// Superscript digits accepted as an exponent, as in `1.2·10⁵`.
+const SUPERSCRIPT_DIGITS : [char;10] = ['⁰','¹','²','³','⁴','⁵','⁶','⁷','⁸','⁹'];
+
+/// Configures which notations [`StandardForm::parse_with_options`] accepts.
+///
+/// [`ParseOptions::default`] is the profile used by `TryFrom<&str>`. [`ParseOptions::strict`] only accepts
+/// machine output such as `1.5e3`, while [`ParseOptions::lenient`] additionally tolerates human input such as `1,5 x 10^3 `.
+///
+/// ```
+/// use standardform::{StandardForm,ParseOptions};
+///
+/// let options = ParseOptions::strict().max_length(32);
+/// assert!(StandardForm::parse_with_options("1.5e3",&options).is_ok());
+/// assert!(StandardForm::parse_with_options("1.5E+3",&options).is_err());
+/// ```
+#[derive(Debug,Clone,PartialEq)]
+pub struct ParseOptions {
+    multipliers : &'static [char],
+    ignore_case : bool,
+    whitespace : bool,
+    decimal_comma : bool,
+    unicode : bool,
+    leading_plus : bool,
+    max_length : Option<usize>,
+    max_exponent : Option<u32>
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            multipliers : &MULTIPLIERS,
+            ignore_case : true,
+            whitespace : true,
+            decimal_comma : false,
+            unicode : true,
+            leading_plus : true,
+            max_length : None,
+            max_exponent : None
+        }
+    }
+}
+
+impl ParseOptions {
+    /// Creates the default profile, the same one used by `TryFrom<&str>`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a profile for trusted machine output that only accepts `mantissa` or `mantissa` `e` `exponent`, e.g. `1.5e3`.
+    #[must_use]
+    pub const fn strict() -> Self {
+        Self {
+            multipliers : &[],
+            ignore_case : false,
+            whitespace : false,
+            decimal_comma : false,
+            unicode : false,
+            leading_plus : false,
+            max_length : None,
+            max_exponent : None
+        }
+    }
+
+    /// Creates a profile for human input, which extends the default profile with a decimal comma, e.g. `1,5 x 10^3`.
+    #[must_use]
+    pub fn lenient() -> Self {
+        Self::default().decimal_comma(true)
+    }
+
+    /// Sets the characters accepted between the mantissa and the `10` of a power, e.g. `*` in `1.2*10^5`.
+    ///
+    /// An empty slice disables the power notation entirely. Non-ASCII characters are only accepted when [`ParseOptions::unicode`] is enabled.
+    #[must_use]
+    pub const fn multipliers(mut self,multipliers : &'static [char]) -> Self {
+        self.multipliers = multipliers;
+        self
+    }
+
+    /// Sets whether `E`, `X`, `INF` and `NAN` are accepted in addition to their lowercase forms.
+    #[must_use]
+    pub const fn ignore_case(mut self,ignore_case : bool) -> Self {
+        self.ignore_case = ignore_case;
+        self
+    }
+
+    /// Sets whether whitespace is allowed around the input and around the multiplication sign.
+    #[must_use]
+    pub const fn whitespace(mut self,whitespace : bool) -> Self {
+        self.whitespace = whitespace;
+        self
+    }
+
+    /// Sets whether `,` is accepted as the decimal separator of the mantissa, e.g. `1,5`.
+    #[must_use]
+    pub const fn decimal_comma(mut self,decimal_comma : bool) -> Self {
+        self.decimal_comma = decimal_comma;
+        self
+    }
+
+    /// Sets whether Unicode multiplication signs such as `×` and superscript exponents such as `10⁵` are accepted.
+    #[must_use]
+    pub const fn unicode(mut self,unicode : bool) -> Self {
+        self.unicode = unicode;
+        self
+    }
+
+    /// Sets whether a leading `+` is accepted on the mantissa and the exponent, e.g. `+1.2e+5`.
+    #[must_use]
+    pub const fn leading_plus(mut self,leading_plus : bool) -> Self {
+        self.leading_plus = leading_plus;
+        self
+    }
+
+    /// Rejects inputs longer than `max_length` bytes before parsing them.
+    #[must_use]
+    pub const fn max_length(mut self,max_length : usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Rejects written exponents whose magnitude is larger than `max_exponent`.
+    #[must_use]
+    pub const fn max_exponent(mut self,max_exponent : u32) -> Self {
+        self.max_exponent = Some(max_exponent);
+        self
+    }
+
+    fn is_sign(&self,character : char) -> bool {
+        character == '-' || (self.leading_plus && character == '+')
+    }
+
+    fn is_exponent_marker(&self,character : char) -> bool {
+        character == 'e' || (self.ignore_case && character == 'E')
+    }
+
+    fn is_multiplier(&self,character : char) -> bool {
+        self.multipliers.contains(&character)
+            && (self.unicode || character.is_ascii())
+            && (self.ignore_case || character != 'X')
+    }
+}
+
+pub(crate) struct Cursor<'a> {
+    pub(crate) input : &'a str,
+    options : &'a ParseOptions,
+    /// Byte offset into `input`, so spans always refer to the original input
+    pub(crate) position : usize,
+    pub(crate) end : usize
+}
+
+impl<'a> Cursor<'a> {
+    pub(crate) fn new(input : &'a str,options : &'a ParseOptions) -> Self {
+        let (position,end) = match options.whitespace {
+            true => {
+                let position = input.len() - input.trim_start().len();
+                (position,input.trim_end().len().max(position))
+            },
+            false => (0,input.len())
+        };
+
+        Self { input , options , position , end }
+    }
+
+    pub(crate) fn rest(&self) -> &'a str {
+        &self.input[self.position..self.end]
+    }
+
+    pub(crate) fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    pub(crate) fn bump(&mut self) -> Option<char> {
+        let character = self.peek()?;
+        self.position += character.len_utf8();
+        Some(character)
+    }
+
+    pub(crate) fn eat(&mut self,expected : impl Fn(char) -> bool) -> Option<char> {
+        match self.peek() {
+            Some(character) if expected(character) => self.bump(),
+            _ => None
+        }
+    }
+
+    pub(crate) fn eat_str(&mut self,expected : &str) -> bool {
+        let matches = self.rest().starts_with(expected);
+        if matches {
+            self.position += expected.len();
+        }
+        matches
+    }
+
+    pub(crate) fn eat_while(&mut self,predicate : impl Fn(char) -> bool) -> &'a str {
+        let start = self.position;
+        while self.eat(&predicate).is_some() {}
+        &self.input[start..self.position]
+    }
+
+    pub(crate) fn error(&self,error : ParsingStandardFormError,span : Range<usize>,expected : &'static str) -> ParsingStandardFormDiagnostic {
+        ParsingStandardFormDiagnostic::new(self.input,span,error,expected)
+    }
+
+    /// Reports the character under the cursor (or the end of input) as unexpected.
+    pub(crate) fn unexpected(&self,expected : &'static str) -> ParsingStandardFormDiagnostic {
+        match self.peek() {
+            Some(character) => self.error(
+                ParsingStandardFormError::UnexpectedCharacter(character),
+                self.position..self.position + character.len_utf8(),
+                expected
+            ),
+            None => self.error(ParsingStandardFormError::UnexpectedEnd,self.position..self.position,expected)
+        }
+    }
+}
+
+/// Parses `input` according to the grammar accepted by `TryFrom<&str> for StandardForm`.
+///
+/// ```text
+/// number   := mantissa exponent?
+/// mantissa := sign? (digits ('.' digits?)? | '.' digits) | sign? ("inf" | "infinity" | "nan")
+/// exponent := ('e' | 'E') sign? digits
+///           | ws? multiplier ws? "10" (('^' | "**") sign? digits | superscript)
+/// ```
+pub(crate) fn parse(input : &str,options : &ParseOptions) -> Result<StandardForm,ParsingStandardFormDiagnostic> {
+    let mut cursor = Cursor::new(input,options);
+
+    if let Some(max_length) = options.max_length.filter(|max_length| input.len() > *max_length) {
+        // only the accepted prefix is kept, so an oversized input isn't copied into the error
+        let limit = floor_char_boundary(input,max_length);
+        return Err(ParsingStandardFormDiagnostic::new(&input[..limit],limit..input.len(),ParsingStandardFormError::TooLong(max_length),"a shorter input"));
+    }
+
+    if cursor.peek().is_none() {
+        return Err(cursor.error(ParsingStandardFormError::Empty,0..input.len(),"a number"));
+    }
+
+    let start = cursor.position;
+
+    let mantissa = match mantissa(&mut cursor)? {
+        Mantissa::Finite(mantissa) => mantissa,
+        Mantissa::Special(value) => return Ok(StandardForm::new(value,0)),
+    };
+
+    let exponent = exponent(&mut cursor)?.map_or(0,|(exponent,_)| exponent);
+
+    if cursor.peek().is_some() {
+        let suggestion = match cursor.peek() {
+            Some('^') => Some("write the power in full, e.g. 1*10^5 instead of 10^5"),
+            Some(',') if !options.decimal_comma => Some("use '.' as the decimal separator"),
+            _ => None
+        };
+
+        return Err(cursor.unexpected("end of input").with_suggestion(suggestion));
+    }
+
+    normalise(mantissa,exponent).map_err(|error| cursor.error(error,start..cursor.end,"a number within the representable range"))
+}
+
+pub(crate) enum Mantissa {
+    Finite(f64),
+    /// Infinity or NaN, which have no meaningful exponent
+    Special(f64)
+}
+
+pub(crate) fn mantissa(cursor : &mut Cursor) -> Result<Mantissa,ParsingStandardFormDiagnostic> {
+    let start = cursor.position;
+
+    let options = cursor.options;
+    let _ = cursor.eat(|character| options.is_sign(character));
+
+    if cursor.peek().is_some_and(char::is_alphabetic) {
+        let word = &cursor.input[cursor.position..cursor.end];
+        let special = match options.ignore_case || word.chars().all(char::is_lowercase) {
+            true => cursor.input[start..cursor.end].parse::<f64>().ok(),
+            false => None
+        };
+
+        let special = special.ok_or_else(|| cursor.unexpected("a digit, 'inf' or 'nan'"))?;
+        cursor.position = cursor.end;
+        return Ok(Mantissa::Special(special));
+    }
+
+    let integer = cursor.eat_while(|character| character.is_ascii_digit());
+    let fraction = match cursor.eat(|character| character == '.' || (options.decimal_comma && character == ',')) {
+        Some(_) => cursor.eat_while(|character| character.is_ascii_digit()),
+        None => ""
+    };
+
+    if integer.is_empty() && fraction.is_empty() {
+        return Err(cursor.unexpected("a digit"));
+    }
+
+    let span = start..cursor.position;
+    let text = &cursor.input[span.clone()];
+    let mantissa = match text.contains(',') {
+        true => text.replace(',',".").parse::<f64>(),
+        false => text.parse::<f64>()
+    };
+    let mantissa = mantissa.map_err(|error| cursor.error(ParsingStandardFormError::Mantissa(error),span.clone(),"a decimal number"))?;
+
+    match mantissa.is_finite() {
+        true => Ok(Mantissa::Finite(mantissa)),
+        false => Err(cursor.error(ParsingStandardFormError::OutOfRange,span,"a mantissa within the range of f64"))
+    }
+}
+
+/// Parses an optional exponent, returning it together with the notation it was written in.
+pub(crate) fn exponent(cursor : &mut Cursor) -> Result<Option<(i32,Notation)>,ParsingStandardFormDiagnostic> {
+    let options = cursor.options;
+
+    if cursor.eat(|character| options.is_exponent_marker(character)).is_some() {
+        return signed_digits(cursor).map(|exponent| Some((exponent,Notation::Scientific)));
+    }
+
+    let start = cursor.position;
+    let _ = cursor.eat_while(|character| options.whitespace && character.is_whitespace());
+
+    if cursor.eat(|character| options.is_multiplier(character)).is_none() {
+        cursor.position = start;
+        return Ok(None);
+    }
+
+    let _ = cursor.eat_while(|character| options.whitespace && character.is_whitespace());
+
+    if !cursor.eat_str("10") {
+        return Err(cursor.unexpected("10"));
+    }
+
+    if cursor.eat_str("^") || cursor.eat_str("**") {
+        return signed_digits(cursor).map(|exponent| Some((exponent,Notation::Power)));
+    }
+
+    if cursor.peek().is_some_and(|character| options.is_multiplier(character)) {
+        return Err(cursor.unexpected("'^' or '**'").with_suggestion(Some("did you mean 10^ instead of 10*?")));
+    }
+
+    match options.unicode {
+        true => superscript(cursor).map(|exponent| Some((exponent,Notation::Superscript))),
+        false => Err(cursor.unexpected("'^' or '**'"))
+    }
+}
+
+pub(crate) fn signed_digits(cursor : &mut Cursor) -> Result<i32,ParsingStandardFormDiagnostic> {
+    let start = cursor.position;
+
+    let options = cursor.options;
+    let _ = cursor.eat(|character| options.is_sign(character));
+
+    if cursor.eat_while(|character| character.is_ascii_digit()).is_empty() {
+        return Err(cursor.unexpected("an integer exponent"));
+    }
+
+    let span = start..cursor.position;
+    check_exponent(cursor,&cursor.input[span.clone()],span)
+}
+
+fn superscript(cursor : &mut Cursor) -> Result<i32,ParsingStandardFormDiagnostic> {
+    let start = cursor.position;
+    let mut exponent = String::new();
+
+    let options = cursor.options;
+
+    if cursor.eat(|character| character == '⁻' || (options.leading_plus && character == '⁺')) == Some('⁻') {
+        exponent.push('-');
+    }
+
+    while let Some(digit) = cursor.eat(|character| SUPERSCRIPT_DIGITS.contains(&character)) {
+        let value = SUPERSCRIPT_DIGITS.iter().position(|superscript| *superscript == digit).expect("digit was matched against `SUPERSCRIPT_DIGITS`");
+        exponent.push(char::from(b'0' + value as u8));
+    }
+
+    if exponent.trim_start_matches('-').is_empty() {
+        return Err(cursor.unexpected("'^', '**' or a superscript exponent"));
+    }
+
+    check_exponent(cursor,&exponent,start..cursor.position)
+}
+
+/// Parses the ASCII digits of an exponent while enforcing [`ParseOptions::max_exponent`].
+fn check_exponent(cursor : &Cursor,exponent : &str,span : Range<usize>) -> Result<i32,ParsingStandardFormDiagnostic> {
+    let exponent : i32 = exponent.parse().map_err(|error| cursor.error(ParsingStandardFormError::Exponent(error),span.clone(),"an exponent between -2147483648 and 2147483647"))?;
+
+    match cursor.options.max_exponent {
+        Some(max_exponent) if exponent.unsigned_abs() > max_exponent => Err(cursor.error(ParsingStandardFormError::ExponentLimit(max_exponent),span,"a smaller exponent")),
+        _ => Ok(exponent)
+    }
+}
+
+/// Normalises the mantissa, reporting an exponent that doesn't fit instead of overflowing like `StandardForm::new`.
+pub(crate) fn normalise(mantissa : f64,exponent : i32) -> Result<StandardForm,ParsingStandardFormError> {
+    if mantissa == 0.0 {
+        return Ok(StandardForm::new(mantissa,exponent));
+    }
+
+    let (mantissa,shift) = crate::sf::decompose(mantissa);
+    let exponent = exponent.checked_add(shift).ok_or(ParsingStandardFormError::OutOfRange)?;
+
+    Ok(StandardForm::new_unchecked(mantissa,exponent))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_parses(input : &str,mantissa : f64,exponent : i32) {
+        assert_parses_with(input,&ParseOptions::default(),mantissa,exponent);
+    }
+
+    fn assert_parses_with(input : &str,options : &ParseOptions,mantissa : f64,exponent : i32) {
+        match parse(input,options) {
+            Ok(standard_form) => {
+                assert_eq!(standard_form.mantissa(), &mantissa, "mantissa of {input:?}");
+                assert_eq!(standard_form.exponent(), &exponent, "exponent of {input:?}");
+            },
+            Err(e) => panic!("Parsing error for {input:?}: {e:?}"),
+        }
+    }
+
+    fn error(input : &str) -> ParsingStandardFormError {
+        error_with(input,&ParseOptions::default())
+    }
+
+    fn error_with(input : &str,options : &ParseOptions) -> ParsingStandardFormError {
+        match parse(input,options) {
+            Ok(standard_form) => panic!("Expected parsing error for {input:?} but got {standard_form}"),
+            Err(diagnostic) => diagnostic.error().clone()
+        }
+    }
+
+    #[test]
+    fn plain_numbers() {
+        assert_parses("42", 4.2, 1);
+        assert_parses("-0.025", -2.5, -2);
+        assert_parses(".5", 5.0, -1);
+        assert_parses("+7", 7.0, 0);
+    }
+
+    #[test]
+    fn e_notation() {
+        assert_parses("1.2E+05", 1.2, 5);
+        assert_parses("-3e-2", -3.0, -2);
+        assert_parses("  1.5e3\n", 1.5, 3);
+    }
+
+    #[test]
+    fn power_notation() {
+        assert_parses("1.2 × 10^5", 1.2, 5);
+        assert_parses("1.2x10^5", 1.2, 5);
+        assert_parses("1.2*10**5", 1.2, 5);
+        assert_parses("2.5*10^-2", 2.5, -2);
+        assert_parses("1.2·10⁵", 1.2, 5);
+        assert_parses("1.2·10⁻¹²", 1.2, -12);
+    }
+
+    #[test]
+    fn special_values() {
+        assert!(parse("inf",&ParseOptions::default()).is_ok_and(|sf| sf.mantissa().is_infinite()));
+        assert!(parse("-NaN",&ParseOptions::default()).is_ok_and(|sf| sf.mantissa().is_nan()));
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(error("   "), ParsingStandardFormError::Empty));
+        assert!(matches!(error("^5"), ParsingStandardFormError::UnexpectedCharacter('^')));
+        assert!(matches!(error("1.2e"), ParsingStandardFormError::UnexpectedEnd));
+        assert!(matches!(error("1.2*10^"), ParsingStandardFormError::UnexpectedEnd));
+        assert!(matches!(error("1.2*11^5"), ParsingStandardFormError::UnexpectedCharacter('1')));
+        assert!(matches!(error("1.2 5"), ParsingStandardFormError::UnexpectedCharacter(' ')));
+        assert!(matches!(error("1e3000000000"), ParsingStandardFormError::Exponent(_)));
+        assert!(matches!(error("500e2147483646"), ParsingStandardFormError::OutOfRange));
+    }
+
+    #[test]
+    fn diagnostics() {
+        let diagnostic = parse(" 1.2*10*5",&ParseOptions::default()).unwrap_err();
+        assert_eq!(diagnostic.span(), 7..8);
+        assert_eq!(diagnostic.expected(), "'^' or '**'");
+        assert_eq!(diagnostic.suggestion(), Some("did you mean 10^ instead of 10*?"));
+
+        let diagnostic = parse("1.2e9999999999",&ParseOptions::default()).unwrap_err();
+        assert_eq!(diagnostic.span(), 4..14);
+
+        assert_eq!(parse("1,5",&ParseOptions::default()).unwrap_err().to_string(), concat!(
+            "Unexpected character ',' at 1..2\n",
+            "  1,5\n",
+            "   ^\n",
+            "  expected end of input\n",
+            "  help: use '.' as the decimal separator"
+        ));
+
+        // the caret counts characters rather than bytes, and a span inside a character is widened to all of it
+        assert_eq!(parse("1×10^5",&ParseOptions::default().unicode(false)).unwrap_err().to_string(), concat!(
+            "Unexpected character '×' at 1..3\n",
+            "  1×10^5\n",
+            "   ^\n",
+            "  expected end of input"
+        ));
+
+        let diagnostic = ParsingStandardFormDiagnostic::new("1×10^5",2..7,ParsingStandardFormError::TooLong(2),"a shorter input");
+        assert_eq!(diagnostic.to_string(), concat!(
+            "Input is longer than the maximum of 2 bytes at 2..7\n",
+            "  1×10^5\n",
+            "   ^^^^^\n",
+            "  expected a shorter input"
+        ));
+    }
+
+    #[test]
+    fn strict_options() {
+        let strict = ParseOptions::strict();
+        assert_parses_with("1.5e3", &strict, 1.5, 3);
+        assert_parses_with("-1.5e-3", &strict, -1.5, -3);
+        assert!(matches!(error_with("1.5E3", &strict), ParsingStandardFormError::UnexpectedCharacter('E')));
+        assert!(matches!(error_with("+1.5e3", &strict), ParsingStandardFormError::UnexpectedCharacter('+')));
+        assert!(matches!(error_with("1.5e+3", &strict), ParsingStandardFormError::UnexpectedCharacter('+')));
+        assert!(matches!(error_with(" 1.5e3", &strict), ParsingStandardFormError::UnexpectedCharacter(' ')));
+        assert!(matches!(error_with("1.5*10^3", &strict), ParsingStandardFormError::UnexpectedCharacter('*')));
+        assert!(matches!(error_with("INF", &strict), ParsingStandardFormError::UnexpectedCharacter('I')));
+    }
+
+    #[test]
+    fn lenient_options() {
+        let lenient = ParseOptions::lenient();
+        assert_parses_with("1,5 x 10^3 ", &lenient, 1.5, 3);
+        assert_parses_with("1.5 × 10³", &lenient, 1.5, 3);
+
+        let ascii = ParseOptions::lenient().unicode(false);
+        assert!(matches!(error_with("1.5 × 10^3", &ascii), ParsingStandardFormError::UnexpectedCharacter(' ')));
+        assert!(matches!(error_with("1.5*10³", &ascii), ParsingStandardFormError::UnexpectedCharacter('³')));
+    }
+
+    #[test]
+    fn limits() {
+        let options = ParseOptions::default().max_length(8).max_exponent(20);
+        assert_parses_with("1.5e20", &options, 1.5, 20);
+        assert!(matches!(error_with("1.5e21", &options), ParsingStandardFormError::ExponentLimit(20)));
+        assert!(matches!(error_with("1.5000000e3", &options), ParsingStandardFormError::TooLong(8)));
+
+        // the span starts on a character boundary and only the accepted prefix is kept
+        let diagnostic = parse("1×10^5",&ParseOptions::default().max_length(2)).unwrap_err();
+        assert_eq!(diagnostic.span(), 1..7);
+        assert_eq!(diagnostic.input(), "1");
+        assert_eq!(diagnostic.to_string(), concat!(
+            "Input is longer than the maximum of 2 bytes at 1..7\n",
+            "  1\n",
+            "   ^\n",
+            "  expected a shorter input"
+        ));
+    }
+}
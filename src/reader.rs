@@ -0,0 +1,387 @@
+use std::io::{BufRead,ErrorKind};
+use std::str::Utf8Error;
+use std::sync::Arc;
+
+use crate::{StandardForm,ParseOptions,ParsingStandardFormError};
+
+/// Reads whitespace or comma separated numbers from a [`BufRead`], one at a time.
+///
+/// The input is consumed straight from the reader's own buffer, and only the number being read is copied, into a
+/// buffer reused for the whole input, so memory use doesn't grow with the length of a line and no allocation happens
+/// per number. Blank lines are skipped, as is everything from a comment marker (`#` by default) to the end of its line.
+/// Parsing errors are wrapped in [`ParsingStandardFormError::At`] with the line and column of the offending character,
+/// as are bytes that aren't valid UTF-8, which are reported as [`ParsingStandardFormError::InvalidBytes`] and skip the
+/// rest of their number. Reading stops after the first [`ParsingStandardFormError::Io`].
+///
+/// Tokens are split at whitespace, so notations that contain spaces such as `1.2 × 10^5` must be written without them.
+///
+/// ```
+/// use standardform::{StandardForm,StandardFormReader};
+///
+/// let input = "# mass, radius\n5.97e24, 6.37e6\n\n7.35e22 1.74e6\n";
+/// let numbers : Vec<_> = StandardFormReader::new(input.as_bytes()).collect::<Result<_,_>>().unwrap();
+///
+/// assert_eq!(numbers.len(), 4);
+/// assert_eq!(numbers[2], StandardForm::new(7.35,22));
+/// ```
+#[derive(Debug)]
+pub struct StandardFormReader<R> {
+    reader : R,
+    tokens : Tokens,
+    finished : bool
+}
+
+/// What happens to the characters up to the next separator.
+#[derive(Debug,Clone,Copy,PartialEq,Eq)]
+enum Skip {
+    /// They are part of a number
+    Nothing,
+    /// They are the rest of a number that has already been reported as invalid
+    Token,
+    /// They are part of a comment, which runs to the end of the line
+    Line
+}
+
+/// Splits characters into numbers, independently of where the chunks read from the underlying reader end.
+#[derive(Debug)]
+struct Tokens {
+    options : ParseOptions,
+    delimiter : char,
+    comment : &'static str,
+    /// The number being read, which may continue in the next chunk
+    token : String,
+    /// Line and column of the first character of `token`
+    start : (usize,usize),
+    /// Line and column of the next character
+    position : (usize,usize),
+    /// The start of a character whose remaining bytes are in the next chunk
+    partial : Vec<u8>,
+    skip : Skip
+}
+
+/// Returns the length of the UTF-8 sequence that starts with `byte`, or 1 if no sequence starts with it.
+const fn sequence_length(byte : u8) -> usize {
+    match byte {
+        0xc0..=0xdf => 2,
+        0xe0..=0xef => 3,
+        0xf0..=0xf7 => 4,
+        _ => 1
+    }
+}
+
+impl Tokens {
+    fn is_separator(&self,character : char) -> bool {
+        character.is_whitespace() || character == self.delimiter
+    }
+
+    /// Parses the number read so far, if there is one.
+    fn finish(&mut self) -> Option<Result<StandardForm,ParsingStandardFormError>> {
+        if self.token.is_empty() {
+            return None;
+        }
+
+        let result = crate::parse::parse(&self.token,&self.options).map_err(|diagnostic| {
+            let (line,column) = self.start;
+
+            ParsingStandardFormError::At {
+                line,
+                column : column + self.token[..diagnostic.span().start].chars().count(),
+                source : Box::new(diagnostic.error().clone())
+            }
+        });
+
+        self.token.clear();
+        Some(result)
+    }
+
+    /// Takes the next character, returning a result once it ends a number.
+    fn push(&mut self,character : char) -> Option<Result<StandardForm,ParsingStandardFormError>> {
+        let (line,column) = self.position;
+
+        self.position = match character {
+            '\n' => (line + 1,1),
+            _ => (line,column + 1)
+        };
+
+        if self.skip == Skip::Line {
+            if character == '\n' {
+                self.skip = Skip::Nothing;
+            }
+
+            return None;
+        }
+
+        if self.is_separator(character) {
+            self.skip = Skip::Nothing;
+            return self.finish();
+        }
+
+        if self.skip == Skip::Token {
+            return None;
+        }
+
+        if self.token.is_empty() {
+            self.start = (line,column);
+        }
+
+        self.token.push(character);
+
+        if !self.comment.is_empty() && self.token.starts_with(self.comment) {
+            self.token.clear();
+            self.skip = Skip::Line;
+        }
+
+        None
+    }
+
+    /// Reports bytes that aren't valid UTF-8 at the current position, unless they are part of a comment.
+    fn invalid(&mut self,error : Utf8Error) -> Option<Result<StandardForm,ParsingStandardFormError>> {
+        let (line,column) = self.position;
+        self.position = (line,column + 1);
+
+        if self.skip != Skip::Nothing {
+            return None;
+        }
+
+        self.token.clear();
+        self.skip = Skip::Token;
+
+        Some(Err(ParsingStandardFormError::At { line , column , source : Box::new(ParsingStandardFormError::InvalidBytes(error)) }))
+    }
+
+    /// Takes characters from `chunk` until one ends a number, returning how many bytes were used and the result.
+    fn feed(&mut self,chunk : &[u8]) -> (usize,Option<Result<StandardForm,ParsingStandardFormError>>) {
+        let mut used = 0;
+
+        while used < chunk.len() {
+            // a character split across chunks is completed from the start of this one
+            let carried = self.partial.len();
+            let length = sequence_length(self.partial.first().copied().unwrap_or(chunk[used]));
+
+            if carried > 0 || used + length > chunk.len() {
+                let taken = (length - carried).min(chunk.len() - used);
+                self.partial.extend_from_slice(&chunk[used..used + taken]);
+
+                if self.partial.len() < length {
+                    return (chunk.len(),None);
+                }
+            }
+
+            let bytes = match self.partial.is_empty() {
+                true => &chunk[used..used + length],
+                false => &self.partial
+            };
+
+            let (consumed,result) = match core::str::from_utf8(bytes) {
+                Ok(character) => {
+                    let character = character.chars().next().expect("a UTF-8 sequence holds a character");
+                    (length,self.push(character))
+                },
+                Err(error) => (error.error_len().unwrap_or(1),self.invalid(error))
+            };
+
+            // the bytes carried over from the previous chunk were already consumed
+            used += consumed.saturating_sub(carried);
+            self.partial.clear();
+
+            if result.is_some() {
+                return (used,result);
+            }
+        }
+
+        (used,None)
+    }
+
+    /// Ends the input, reporting a character cut short by it and parsing the last number.
+    fn end(&mut self) -> Option<Result<StandardForm,ParsingStandardFormError>> {
+        if !self.partial.is_empty() {
+            let error = core::str::from_utf8(&self.partial).expect_err("an incomplete character is not valid UTF-8");
+            self.partial.clear();
+
+            if let Some(result) = self.invalid(error) {
+                return Some(result);
+            }
+        }
+
+        self.finish()
+    }
+}
+
+impl<R : BufRead> StandardFormReader<R> {
+    /// Creates a reader that parses numbers with the default [`ParseOptions`], separated by whitespace or `,`.
+    pub fn new(reader : R) -> Self {
+        Self {
+            reader,
+            tokens : Tokens {
+                options : ParseOptions::default(),
+                delimiter : ',',
+                comment : "#",
+                token : String::new(),
+                start : (1,1),
+                position : (1,1),
+                partial : Vec::with_capacity(4),
+                skip : Skip::Nothing
+            },
+            finished : false
+        }
+    }
+
+    /// Sets the options used to parse each number.
+    #[must_use]
+    pub fn options(mut self,options : ParseOptions) -> Self {
+        self.tokens.options = options;
+        self
+    }
+
+    /// Sets the character that separates numbers in addition to whitespace, e.g. `;` for files that use a decimal comma.
+    #[must_use]
+    pub fn delimiter(mut self,delimiter : char) -> Self {
+        self.tokens.delimiter = delimiter;
+        self
+    }
+
+    /// Sets the marker that starts a comment running to the end of the line, e.g. `//` or `%`.
+    #[must_use]
+    pub fn comment(mut self,comment : &'static str) -> Self {
+        self.tokens.comment = comment;
+        self
+    }
+
+    /// Returns the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R : BufRead> Iterator for StandardFormReader<R> {
+    type Item = Result<StandardForm,ParsingStandardFormError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.finished {
+            let chunk = match self.reader.fill_buf() {
+                Ok(chunk) => chunk,
+                Err(error) if error.kind() == ErrorKind::Interrupted => continue,
+                Err(error) => {
+                    self.finished = true;
+                    return Some(Err(ParsingStandardFormError::Io(Arc::new(error))));
+                }
+            };
+
+            if chunk.is_empty() {
+                self.finished = true;
+                return self.tokens.end();
+            }
+
+            let (used,result) = self.tokens.feed(chunk);
+            self.reader.consume(used);
+
+            if result.is_some() {
+                return result;
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::{self,Read};
+
+    fn read(input : &str) -> Vec<Result<StandardForm,ParsingStandardFormError>> {
+        StandardFormReader::new(input.as_bytes()).collect()
+    }
+
+    #[test]
+    fn separators() {
+        let numbers : Vec<_> = read("1e3,2e3 , 3e3\t4e3\r\n5\n").into_iter().map(Result::unwrap).collect();
+        assert_eq!(numbers, [
+            StandardForm::new(1.0,3),
+            StandardForm::new(2.0,3),
+            StandardForm::new(3.0,3),
+            StandardForm::new(4.0,3),
+            StandardForm::new(5.0,0)
+        ]);
+    }
+
+    #[test]
+    fn comments_and_blank_lines() {
+        let numbers = read("# header\n\n   \n1.5e3 # trailing\n#2e3\n-7\n");
+        assert_eq!(numbers.len(), 2);
+
+        let numbers : Vec<_> = StandardFormReader::new("% header\n1.5;2,5 % trailing\n".as_bytes())
+            .options(ParseOptions::lenient())
+            .delimiter(';')
+            .comment("%")
+            .collect::<Result<_,_>>()
+            .unwrap();
+        assert_eq!(numbers, [StandardForm::new(1.5,0),StandardForm::new(2.5,0)]);
+    }
+
+    #[test]
+    fn positions() {
+        let numbers = read("1e3\n\n2e3, 4é5, 3e9999999999\n");
+
+        assert!(numbers[0].is_ok());
+        assert!(numbers[1].is_ok());
+        assert!(matches!(&numbers[2], Err(ParsingStandardFormError::At { line : 3 , column : 7 , source }) if matches!(**source, ParsingStandardFormError::UnexpectedCharacter('é'))));
+        assert!(matches!(&numbers[3], Err(ParsingStandardFormError::At { line : 3 , column : 13 , .. })));
+        assert_eq!(numbers[3].as_ref().unwrap_err().to_string(), "Failed parsing exponent due to number too large to fit in target type at line 3, column 13");
+    }
+
+    #[test]
+    fn chunk_boundaries() {
+        let input = "# ünïcödé header\n1.2·10⁵, -3.5e-2\n4é5 6e7 # trailing\n";
+        let expected : Vec<_> = read(input).into_iter().map(|result| result.map_err(|error| error.to_string())).collect();
+
+        assert_eq!(expected.len(), 4);
+        assert_eq!(expected[0], Ok(StandardForm::new(1.2,5)));
+        assert_eq!(expected[3], Ok(StandardForm::new(6.0,7)));
+
+        // every split of the input between chunks, including inside multi-byte characters and numbers
+        for capacity in 1..8 {
+            let reader = io::BufReader::with_capacity(capacity,input.as_bytes());
+            let numbers : Vec<_> = StandardFormReader::new(reader).map(|result| result.map_err(|error| error.to_string())).collect();
+            assert_eq!(numbers, expected, "capacity {capacity}");
+        }
+    }
+
+    #[test]
+    fn long_lines() {
+        let line = "1.5e3,".repeat(100_000);
+        let reader = io::BufReader::with_capacity(64,line.as_bytes());
+
+        let mut numbers = StandardFormReader::new(reader);
+        assert!(numbers.by_ref().all(|number| number.unwrap() == StandardForm::new(1.5,3)));
+
+        // only the reader's own buffer and the longest number are held
+        assert!(numbers.tokens.token.capacity() < 64);
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let numbers = StandardFormReader::new(&b"1e3 2\xffe3\xfe 4e3\n# caf\xe9\n5e\xe2\x82"[..]).collect::<Vec<_>>();
+
+        assert_eq!(numbers.len(), 4);
+        assert!(matches!(&numbers[1], Err(ParsingStandardFormError::At { line : 1 , column : 6 , source }) if matches!(**source, ParsingStandardFormError::InvalidBytes(_))));
+        assert_eq!(numbers[2].as_ref().unwrap(), &StandardForm::new(4.0,3));
+        assert!(matches!(&numbers[3], Err(ParsingStandardFormError::At { line : 3 , column : 3 , source }) if matches!(**source, ParsingStandardFormError::InvalidBytes(_))));
+    }
+
+    struct Failing;
+
+    impl Read for Failing {
+        fn read(&mut self,_ : &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::other("disk on fire"))
+        }
+    }
+
+    #[test]
+    fn io_errors() {
+        let mut reader = StandardFormReader::new(io::BufReader::new(Failing));
+        assert!(matches!(reader.next(), Some(Err(ParsingStandardFormError::Io(_)))));
+        assert!(reader.next().is_none());
+    }
+}
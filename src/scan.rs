@@ -0,0 +1,238 @@
+use core::ops::Range;
+
+use crate::{StandardForm,ParseOptions,ParsingStandardFormError};
+use crate::parse::{Cursor,Mantissa,mantissa,exponent,normalise};
+
+/// How the exponent of a number was written.
+#[derive(Debug,Clone,Copy,PartialEq,Eq,Hash)]
+pub enum Notation {
+    /// A plain decimal number without an exponent, e.g. `42` or `0.5`.
+    Decimal,
+    /// E notation, e.g. `6.37e6` or `1.2E-3`.
+    Scientific,
+    /// A power of ten written with `^` or `**`, e.g. `5.97×10^24` or `1.2*10**5`.
+    Power,
+    /// A power of ten written with a superscript exponent, e.g. `1.2·10⁵`.
+    Superscript
+}
+
+/// A number found by [`Scanner`] in a larger text.
+#[derive(Debug,Clone,PartialEq)]
+pub struct ScannedNumber<'a> {
+    value : StandardForm,
+    span : Range<usize>,
+    lexeme : &'a str,
+    notation : Notation
+}
+
+impl<'a> ScannedNumber<'a> {
+    /// Returns the parsed number.
+    #[must_use]
+    pub const fn value(&self) -> &StandardForm {
+        &self.value
+    }
+
+    /// Returns the byte range of the number in the scanned text.
+    #[must_use]
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    /// Returns the number as it was written in the scanned text.
+    #[must_use]
+    pub const fn lexeme(&self) -> &'a str {
+        self.lexeme
+    }
+
+    /// Returns the notation the number was written in.
+    #[must_use]
+    pub const fn notation(&self) -> Notation {
+        self.notation
+    }
+}
+
+/// An iterator over the numbers in a text, created by [`StandardForm::scan`] and [`StandardForm::scan_with_options`].
+///
+/// Numbers are recognised with the same grammar as [`StandardForm::parse_with_options`], except that they may be
+/// surrounded by other text. A number only starts at a word boundary, so the `2` of `H2O` is skipped, and an
+/// incomplete exponent such as the `x 3` of `2 x 3` leaves the mantissa on its own. Infinity and NaN are never matched,
+/// and numbers that don't fit in a `StandardForm` are skipped.
+///
+/// ```
+/// use standardform::{StandardForm,Notation};
+///
+/// let numbers : Vec<_> = StandardForm::scan("mass was 5.97×10^24 kg and r=6.37e6 m").collect();
+///
+/// assert_eq!(numbers[0].lexeme(), "5.97×10^24");
+/// assert_eq!(numbers[0].notation(), Notation::Power);
+/// assert_eq!(numbers[1].value(), &StandardForm::new(6.37,6));
+/// assert_eq!(numbers[1].span(), 30..36);
+/// ```
+#[derive(Debug,Clone)]
+pub struct Scanner<'a> {
+    input : &'a str,
+    options : ParseOptions,
+    position : usize
+}
+
+impl<'a> Scanner<'a> {
+    fn starts_number(&self,position : usize) -> bool {
+        let before = self.input[..position].chars().next_back();
+        if before.is_some_and(|character| character.is_alphanumeric() || character == '_' || character == '.') {
+            return false;
+        }
+
+        let mut after = self.input[position..].chars();
+        let first = after.next();
+        let first = match first {
+            Some('-' | '+') => after.next(),
+            _ => first
+        };
+
+        match first {
+            Some('.') => after.next().is_some_and(|character| character.is_ascii_digit()),
+            Some(character) => character.is_ascii_digit(),
+            None => false
+        }
+    }
+
+    /// Reads the number starting at `start`, returning where the scanner should continue and the number, if any.
+    fn read(&self,start : usize) -> (usize,Option<ScannedNumber<'a>>) {
+        let mut cursor = Cursor::new(self.input,&self.options);
+        cursor.position = start;
+        cursor.end = self.input.len();
+
+        let mantissa = match mantissa(&mut cursor) {
+            Ok(Mantissa::Finite(mantissa)) => mantissa,
+            _ => return (cursor.position.max(start + 1),None)
+        };
+
+        let mantissa_end = cursor.position;
+
+        let (exponent,notation) = match exponent(&mut cursor) {
+            Ok(Some(exponent)) => exponent,
+            Ok(None) => (0,Notation::Decimal),
+            Err(diagnostic) => match diagnostic.error() {
+                ParsingStandardFormError::UnexpectedCharacter(_) | ParsingStandardFormError::UnexpectedEnd => {
+                    cursor.position = mantissa_end;
+                    (0,Notation::Decimal)
+                },
+                _ => return (cursor.position,None)
+            }
+        };
+
+        // A full stop that ends a sentence is not part of the number
+        if notation == Notation::Decimal && self.input[..cursor.position].ends_with('.') {
+            cursor.position -= 1;
+        }
+
+        let span = start..cursor.position;
+        let number = normalise(mantissa,exponent).ok().map(|value| ScannedNumber {
+            value,
+            lexeme : &self.input[span.clone()],
+            span,
+            notation
+        });
+
+        (cursor.position,number)
+    }
+}
+
+impl<'a> Iterator for Scanner<'a> {
+    type Item = ScannedNumber<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(character) = self.input[self.position..].chars().next() {
+            if !self.starts_number(self.position) {
+                self.position += character.len_utf8();
+                continue;
+            }
+
+            let (position,number) = self.read(self.position);
+            self.position = position;
+
+            if number.is_some() {
+                return number;
+            }
+        }
+
+        None
+    }
+}
+
+impl StandardForm {
+    /// Returns an iterator over every number in `input` that the default grammar recognises, such as `6.37e6` or `5.97×10^24`.
+    ///
+    /// See [`Scanner`] for the rules used to find numbers in free text.
+    #[must_use]
+    pub fn scan(input : &str) -> Scanner<'_> {
+        Self::scan_with_options(input,&ParseOptions::default())
+    }
+
+    /// Returns an iterator over every number in `input` that `options` accepts.
+    #[must_use]
+    pub fn scan_with_options<'a>(input : &'a str,options : &ParseOptions) -> Scanner<'a> {
+        Scanner { input , options : options.clone() , position : 0 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lexemes(input : &str) -> Vec<&str> {
+        StandardForm::scan(input).map(|number| number.lexeme()).collect()
+    }
+
+    #[test]
+    fn notations() {
+        let numbers : Vec<_> = StandardForm::scan("mass was 5.97×10^24 kg and r=6.37e6 m, n = 12, a = 1.2·10⁻³").collect();
+
+        assert_eq!(numbers.len(), 4);
+        assert_eq!(numbers[0].value(), &StandardForm::new(5.97,24));
+        assert_eq!(numbers[0].span(), 9..20);
+        assert_eq!(numbers[1].notation(), Notation::Scientific);
+        assert_eq!(numbers[2].notation(), Notation::Decimal);
+        assert_eq!(numbers[2].value(), &StandardForm::new(1.2,1));
+        assert_eq!(numbers[3].notation(), Notation::Superscript);
+        assert_eq!(numbers[3].lexeme(), "1.2·10⁻³");
+    }
+
+    #[test]
+    fn boundaries() {
+        assert_eq!(lexemes("H2O at -4.5 degrees"), ["-4.5"]);
+        assert_eq!(lexemes("5-3 and v2.5"), ["5", "3"]);
+        assert_eq!(lexemes("it was 5. Then .5 more"), ["5", ".5"]);
+        assert_eq!(lexemes("2 x 3 and 1em"), ["2", "3", "1"]);
+        assert_eq!(lexemes("inf nan banana"), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert_eq!(lexemes("1e3000000000 then 7"), ["7"]);
+        assert_eq!(lexemes("500e2147483646 then 7"), ["7"]);
+    }
+
+    #[test]
+    fn options() {
+        let options = ParseOptions::strict();
+        let numbers : Vec<_> = StandardForm::scan_with_options("1.5E3 and 2e3 and +4",&options).map(|number| number.lexeme()).collect();
+        assert_eq!(numbers, ["1.5", "2e3", "4"]);
+    }
+
+    #[test]
+    fn replace() {
+        let input = "r=6.37e6 m";
+        let mut output = String::new();
+        let mut last = 0;
+
+        for number in StandardForm::scan(input) {
+            output.push_str(&input[last..number.span().start]);
+            output.push_str(&number.value().to_plain_string());
+            last = number.span().end;
+        }
+        output.push_str(&input[last..]);
+
+        assert_eq!(output, "r=6370000 m");
+    }
+}
@@ -1,5 +1,6 @@
 use core::ops::{Add,Sub,Mul,Div,AddAssign,SubAssign,MulAssign,DivAssign,Neg,Rem,RemAssign};
 use core::cmp::Ordering;
+use core::fmt::Write;
 
 #[cfg(feature="js")]
 use wasm_bindgen::prelude::*;
@@ -11,12 +12,16 @@ use uniffi::*;
 ///
 /// The `Standardform` struct holds the significand (mantissa) of the number 
 /// and an exponent that determines the power of 10 by which the significand should be multiplied.
-#[derive(Clone,PartialEq)]
+///
+/// Every value is kept in a canonical form, so that equal numbers compare and hash equal: the mantissa lies in `[1, 10)`,
+/// zero is always `0e0`, infinities have an exponent of 0 and there is a single NaN. Unlike `f64`, NaN equals itself
+/// and orders above every other number, which makes `StandardForm` usable as a key in sorted and hashed collections.
+#[derive(Clone,Copy)]
 #[cfg_attr(feature="js", wasm_bindgen)]
 #[cfg_attr(feature="bindings", derive(Object))]
 pub struct StandardForm  {
     mantissa : f64,
-    exponent : i8 
+    exponent : i32
 }
 
 #[cfg_attr(feature="js", wasm_bindgen)]
@@ -33,10 +38,12 @@ impl StandardForm {
     /// this method will adjust them while maintaining the value of the number represented. The adjustment
     /// ensures that the mantissa is between 1 (inclusive) and 10 (exclusive) and the exponent is such that
     /// the product of mantissa and 10 raised to the exponent yields the original number.
+    ///
+    /// Like `f64`, a number whose exponent no longer fits in an `i32` once normalised becomes infinite or zero.
     #[must_use]
     #[cfg_attr(feature="js", wasm_bindgen(constructor))]
     #[cfg_attr(feature="bindings", constructor)]
-    pub fn new(mantissa : f64,exponent : i8) -> Self {
+    pub fn new(mantissa : f64,exponent : i32) -> Self {
         let mut instance = Self::new_unchecked(mantissa,exponent);
         instance.adjust();
         instance
@@ -57,33 +64,95 @@ impl StandardForm {
         self.into()
     }
 
-    pub(crate) const fn new_unchecked(mantissa : f64,exponent : i8) -> Self { 
+    pub(crate) const fn new_unchecked(mantissa : f64,exponent : i32) -> Self {
         Self { mantissa , exponent }
     }
 
-    fn in_range(&self) -> bool {
-        (self.mantissa >= 1.0 && self.mantissa <= 10.0) || (self.mantissa >= -10.0 && self.mantissa <= -1.0)
+    /// Creates a number in its canonical form like [`StandardForm::new`], but returns `None` instead of panicking if
+    /// the exponent doesn't fit once the mantissa is normalised.
+    pub(crate) fn checked_new(mantissa : f64,exponent : i32) -> Option<Self> {
+        if mantissa == 0.0 || !mantissa.is_finite() {
+            return Some(Self::new(mantissa,0));
+        }
+
+        let (mantissa,shift) = match (1.0..10.0).contains(&mantissa.abs()) {
+            true => (mantissa,0),
+            false => decompose(mantissa)
+        };
+
+        exponent.checked_add(shift).map(|exponent| Self::new_unchecked(mantissa,exponent))
+    }
+
+    /// Creates a number in its canonical form from an exponent that may lie outside the range of `i32`, turning
+    /// exponents that are too large into infinity and ones that are too small into zero, like `f64` does.
+    pub(crate) fn saturating_new(mantissa : f64,exponent : i64) -> Self {
+        if mantissa == 0.0 || !mantissa.is_finite() {
+            return Self::new(mantissa,0);
+        }
+
+        let (mantissa,shift) = decompose(mantissa);
+
+        match i32::try_from(exponent.saturating_add(i64::from(shift))) {
+            Ok(exponent) => Self::new_unchecked(mantissa,exponent),
+            Err(_) if exponent > 0 => Self::new(mantissa * f64::INFINITY,0),
+            Err(_) => Self::new(0.0,0)
+        }
+    }
+
+    /// Builds a number from the text of a decimal mantissa without exponent, such as `-12.5`, and an exponent.
+    ///
+    /// Returns `None` if the mantissa isn't valid or the exponent would overflow while normalising.
+    #[cfg(any(feature="nom",feature="winnow",feature="chumsky"))]
+    pub(crate) fn from_parts(mantissa : &str,exponent : i32) -> Option<Self> {
+        let value : f64 = mantissa.parse().ok().filter(|value : &f64| value.is_finite())?;
+
+        // a mantissa such as `9.99…9` may round up to 10, carrying into the exponent
+        Self::checked_new(value,exponent)
     }
 
+    /// Brings the number into its canonical form, normalising the mantissa into `[1, 10)`, see [`decompose`].
     fn adjust(&mut self) {
-        if self.in_range() || self.mantissa == 0.0 {
+        if self.mantissa == 0.0 || self.mantissa.is_infinite() {
+            // also turns -0 into 0
+            self.mantissa += 0.0;
+            self.exponent = 0;
             return;
         }
 
-        // means its things like 0.2323 not -700
-        match self.mantissa > -1.0 && self.mantissa < 1.0 {
-            true => while !self.in_range() {
-                self.mantissa *= 10.0;
-                self.exponent -= 1; 
-            },
-            false => while !self.in_range() {
-                self.mantissa /= 10.0;
-                self.exponent += 1; 
-            }
+        if self.mantissa.is_nan() {
+            self.mantissa = f64::NAN;
+            self.exponent = 0;
+            return;
+        }
+
+        if (1.0..10.0).contains(&self.mantissa.abs()) {
+            return;
+        }
+
+        let (mantissa,shift) = decompose(self.mantissa);
+
+        match self.exponent.checked_add(shift) {
+            Some(exponent) => (self.mantissa,self.exponent) = (mantissa,exponent),
+            None => *self = Self::saturating_new(mantissa,i64::from(self.exponent) + i64::from(shift))
         }
     }
 }
 
+/// Splits a finite, non-zero `value` into a mantissa in `[1, 10)` and a power of ten.
+///
+/// Both are taken from the shortest decimal digits of `value`, so the mantissa is exactly the number those digits
+/// spell, e.g. `3e-4` yields `3` rather than the `2.9999999999999996` a multiplication by `10^4` would round to.
+pub(crate) fn decompose(value : f64) -> (f64,i32) {
+    let mut buffer = crate::StandardFormBuffer::new();
+    write!(buffer,"{value:e}").expect("`{:e}` of an f64 fits in the buffer");
+
+    let (mantissa,exponent) = buffer.split_once('e').expect("`{:e}` always contains an exponent");
+    (
+        mantissa.parse().expect("`{:e}` always has a decimal mantissa"),
+        exponent.parse().expect("`{:e}` always has an integer exponent")
+    )
+}
+
 impl StandardForm {
     /// Returns a reference to the StandardForm representing the significand (mantissa) of the number.
     #[cfg(not(feature="js"))]
@@ -102,33 +171,194 @@ impl StandardForm {
     /// Returns the exponent that determines the power of 10 by which the significand should be multiplied.
     #[cfg(not(feature="js"))]
     #[must_use]
-    pub const fn exponent(&self) -> &i8 {
+    pub const fn exponent(&self) -> &i32 {
         &self.exponent
     }  
 
     /// Returns the exponent that determines the power of 10 by which the significand should be multiplied.
     #[must_use]
     #[cfg(feature="js")]
-    pub const fn exponent(&self) -> &i8 {
+    pub const fn exponent(&self) -> &i32 {
         &self.exponent
     }      
 }
 
+#[cfg(feature="std")]
 #[cfg_attr(feature="js", wasm_bindgen)]
 impl StandardForm {
     /// Returns the string representation of the number in scientific notation.
     #[must_use]
     #[cfg_attr(feature="js", wasm_bindgen)]
     pub fn to_scientific_notation(&self) -> String {
-        format!("{}e{}", self.mantissa, self.exponent)
+        self.to_scientific_buffer().to_string()
     }
         
     /// Returns the string representation of the number in engineering notation.
     #[must_use]
     #[cfg_attr(feature="js", wasm_bindgen)]
     pub fn to_engineering_notation(&self) -> String {
-        format!("{}*10^{}", self.mantissa, self.exponent)
-    }    
+        self.to_engineering_buffer().to_string()
+    }
+
+    /// Returns the exact decimal expansion of the number without an exponent.
+    ///
+    /// The digits are the shortest ones that round-trip the mantissa, so `1.5e99` is rendered as `15`
+    /// followed by 98 zeros rather than exposing the binary artefacts of `f64`.
+    #[must_use]
+    #[cfg_attr(feature="js", wasm_bindgen)]
+    pub fn to_plain_string(&self) -> String {
+        self.expand(None,None)
+    }
+
+    /// Returns the decimal expansion of the number rounded (half away from zero) to `fraction_digits` digits after the decimal point.
+    ///
+    /// At most `i32::MAX` digits are written after the decimal point.
+    #[must_use]
+    #[cfg_attr(feature="js", wasm_bindgen)]
+    pub fn to_fixed_string(&self,fraction_digits : usize) -> String {
+        self.expand(Some(fraction_digits),None)
+    }
+
+    /// Returns the exact decimal expansion of the number like [`StandardForm::to_plain_string`], with the integer digits grouped in threes by `separator`.
+    #[must_use]
+    #[cfg_attr(feature="js", wasm_bindgen)]
+    pub fn to_grouped_string(&self,separator : char) -> String {
+        self.expand(None,Some(separator))
+    }
+
+    /// Returns a speech-friendly representation of the number, suitable for screen readers.
+    ///
+    /// For example `6.02e23` is rendered as "6.02 times 10 to the power of 23" and
+    /// `-1.5e-3` as "minus 1.5 times 10 to the power of minus 3".
+    #[must_use]
+    #[cfg_attr(feature="js", wasm_bindgen)]
+    pub fn to_speech_string(&self) -> String {
+        fn signed<T : PartialOrd + Default + core::fmt::Display + Neg<Output = T>>(value : T) -> String {
+            match value < T::default() {
+                true => format!("minus {}", -value),
+                false => format!("{value}")
+            }
+        }
+
+        if self.mantissa.is_nan() {
+            return "not a number".to_string();
+        }
+
+        if self.mantissa.is_infinite() {
+            return match self.mantissa.is_sign_negative() {
+                true => "minus infinity".to_string(),
+                false => "infinity".to_string()
+            };
+        }
+
+        match self.exponent {
+            0 => signed(self.mantissa),
+            1 => format!("{} times 10", signed(self.mantissa)),
+            exponent => format!("{} times 10 to the power of {}", signed(self.mantissa), signed(exponent))
+        }
+    }
+
+    /// Returns a label for the `aria-label` attribute so assistive technologies announce the number
+    /// in spoken form rather than reading out the raw notation.
+    #[must_use]
+    #[cfg(feature="js")]
+    #[wasm_bindgen]
+    pub fn aria_label(&self) -> String {
+        self.to_speech_string()
+    }
+}
+
+#[cfg(feature="std")]
+impl StandardForm {
+    fn expand(&self,fraction_digits : Option<usize>,separator : Option<char>) -> String {
+        if !self.mantissa.is_finite() {
+            return format!("{}",self.mantissa);
+        }
+
+        // `{:e}` yields the shortest digits that round-trip, e.g. "1.234e0"
+        let scientific = format!("{:e}",self.mantissa.abs());
+        let (significand,exponent) = scientific.split_once('e').expect("`{:e}` always contains an exponent");
+
+        let mut digits : Vec<u8> = significand.bytes().filter(u8::is_ascii_digit).map(|digit| digit - b'0').collect();
+        // number of digits before the decimal point
+        let mut point = exponent.parse::<i32>().expect("`{:e}` always has an integer exponent").saturating_add(self.exponent).saturating_add(1);
+        let fraction_digits = fraction_digits.map(|fraction_digits| i32::try_from(fraction_digits).unwrap_or(i32::MAX));
+
+        if let Some(fraction_digits) = fraction_digits {
+            let keep = point.saturating_add(fraction_digits);
+
+            if keep < 0 {
+                digits.clear();
+            }
+            else if (keep as usize) < digits.len() {
+                let round_up = digits[keep as usize] >= 5;
+                digits.truncate(keep as usize);
+
+                if round_up {
+                    let mut index = digits.len();
+                    loop {
+                        if index == 0 {
+                            digits.insert(0,1);
+                            point = point.saturating_add(1);
+                            break;
+                        }
+
+                        index -= 1;
+
+                        match digits[index] == 9 {
+                            true => digits[index] = 0,
+                            false => {
+                                digits[index] += 1;
+                                break;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        let digit_at = |index : i32| match index >= 0 && (index as usize) < digits.len() {
+            true => char::from(b'0' + digits[index as usize]),
+            false => '0'
+        };
+
+        let mut integer : String = match point > 0 {
+            true => (0..point).map(digit_at).collect(),
+            false => String::from("0")
+        };
+
+        if let Some(separator) = separator {
+            let length = integer.len();
+            integer = integer.chars()
+                .enumerate()
+                .flat_map(|(index,digit)| {
+                    let group = index != 0 && (length - index).is_multiple_of(3);
+                    group.then_some(separator).into_iter().chain(core::iter::once(digit))
+                })
+                .collect();
+        }
+
+        let fraction_length = match fraction_digits {
+            Some(fraction_digits) => fraction_digits,
+            None => (digits.len() as i32).saturating_sub(point).max(0)
+        };
+
+        let fraction : String = (0..fraction_length).map(|index| digit_at(point.saturating_add(index))).collect();
+        let fraction = match fraction_digits {
+            Some(_) => fraction.as_str(),
+            None => fraction.trim_end_matches('0')
+        };
+
+        let sign = match self.mantissa < 0.0 && digits.iter().any(|digit| *digit != 0) {
+            true => "-",
+            false => ""
+        };
+
+        match fraction.is_empty() {
+            true => format!("{sign}{integer}"),
+            false => format!("{sign}{integer}.{fraction}")
+        }
+    }
 }
 
 impl Default for StandardForm {
@@ -138,12 +368,16 @@ impl Default for StandardForm {
     }
 }
 
+impl PartialEq for StandardForm {
+    fn eq(&self, other: &Self) -> bool {
+        self.exponent == other.exponent
+            && (self.mantissa == other.mantissa || (self.mantissa.is_nan() && other.mantissa.is_nan()))
+    }
+}
+
 impl PartialOrd for StandardForm {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        match self.exponent == other.exponent {
-            true => self.mantissa.partial_cmp(&other.mantissa),
-            false => self.exponent.partial_cmp(&other.exponent)
-        }
+        Some(self.cmp(other))
     }
 }
 
@@ -151,17 +385,43 @@ impl Eq for StandardForm {}
 
 impl Ord for StandardForm {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap()
+        match (self.mantissa.is_nan(),other.mantissa.is_nan()) {
+            (true,true) => return Ordering::Equal,
+            (true,false) => return Ordering::Greater,
+            (false,true) => return Ordering::Less,
+            (false,false) => {}
+        }
+
+        let sign = |mantissa : f64| i8::from(mantissa > 0.0) - i8::from(mantissa < 0.0);
+
+        match sign(self.mantissa).cmp(&sign(other.mantissa)) {
+            Ordering::Equal => {},
+            ordering => return ordering
+        }
+
+        // zero and infinities have an exponent of 0, but their mantissa alone already decides
+        if self.mantissa == 0.0 || self.mantissa.is_infinite() || other.mantissa.is_infinite() {
+            return self.mantissa.total_cmp(&other.mantissa);
+        }
+
+        let magnitude = self.exponent.cmp(&other.exponent).then(self.mantissa.abs().total_cmp(&other.mantissa.abs()));
+
+        match self.mantissa < 0.0 {
+            true => magnitude.reverse(),
+            false => magnitude
+        }
     }
 }
 
 impl core::fmt::Display for StandardForm {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-        if self.exponent > 4 {
-            return write!(f,"{}",self.to_scientific_notation());
-        };
+        // smaller numbers would print hundreds of zeros, so only the ones an `i8` exponent could hold are written out
+        #[cfg(feature="std")]
+        if (-128..=4).contains(&self.exponent) {
+            return write!(f,"{}",self.to_plain_string());
+        }
 
-        write!(f,"{}",self.mantissa * 10_f64.powi(self.exponent as i32) as f64)
+        write!(f,"{}e{}",self.mantissa,self.exponent)
     }
 }
 
@@ -174,7 +434,11 @@ impl core::fmt::Debug for StandardForm {
 impl From<StandardForm> for f64 {
     #[must_use]
     fn from(value: StandardForm) -> Self {
-       value.mantissa * 10_f64.powi(value.exponent as i32)
+        // 10^-308 and below aren't normal, so subnormal results are scaled in two steps
+        match value.exponent < -300 {
+            true => value.mantissa * 1e-300 * pow10(value.exponent.saturating_add(300)),
+            false => value.mantissa * pow10(value.exponent)
+        }
     }
 }
 
@@ -182,25 +446,25 @@ impl From<StandardForm> for f64 {
 impl TryFrom<&str> for StandardForm {
     type Error = crate::ParsingStandardFormError;
 
-    #[must_use]
+    /// Parses a number written as `1.2`, `1.2e5`, `1.2E+05`, `1.2*10^5`, `1.2*10**5`, `1.2 × 10^5`, `1.2x10^5` or `1.2·10⁵`.
+    ///
+    /// Surrounding whitespace is ignored, and whitespace is allowed around the multiplication sign.
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        if let Ok(number) = value.parse::<f64>() {
-            return Ok(number.into());
-        }
+        crate::parse::parse(value,&crate::ParseOptions::default()).map_err(Into::into)
+    }
+}
 
-        if let Some(index) = value.find('e') {
-            let m_str : f64 = value[0..index].parse()?;
-            let e_str : i8 = value[index + 1..].parse()?;
-            return Ok(StandardForm::new(m_str,e_str));
-        }
-        
-        if let Some(index) = value.find('^') {
-            let m_str : f64 = value[0..index - 3].parse()?;
-            let e_str : i8 = value[index + 1..].parse()?;
-            return Ok(StandardForm::new(m_str,e_str));
-        }
+#[cfg(feature="std")]
+impl StandardForm {
+    /// Parses `input` with the same grammar as `TryFrom<&str>`, but on failure reports the offending byte span,
+    /// the expected token and, where possible, a suggested fix.
+    pub fn parse_with_diagnostics(input : &str) -> Result<Self,crate::ParsingStandardFormDiagnostic> {
+        crate::parse::parse(input,&crate::ParseOptions::default())
+    }
 
-        Err(crate::ParsingStandardFormError::InvalidFormat)
+    /// Parses `input` with the notations enabled in `options`, reporting failures like [`StandardForm::parse_with_diagnostics`].
+    pub fn parse_with_options(input : &str,options : &crate::ParseOptions) -> Result<Self,crate::ParsingStandardFormDiagnostic> {
+        crate::parse::parse(input,options)
     }
 }
 
@@ -217,95 +481,248 @@ impl Neg for StandardForm {
     type Output = Self;
     #[must_use]
     fn neg(self) -> Self::Output {
-        Self::new_unchecked(-self.mantissa,self.exponent)
+        Self::new(-self.mantissa,self.exponent)
     }
 }
 
 impl Rem for StandardForm {
     type Output = Self;
-    #[must_use]
+
+    /// Returns the remainder of the truncated division, which has the sign of `self` like `f64`'s `%`.
+    ///
+    /// The remainder is computed on the decimal mantissas, so `0.7 % 0.3` is exactly `0.1` and `1e100 % 7` is `4`.
     fn rem(self,other : Self) -> Self::Output {
-        self.clone() - (self / other.clone() * other) 
+        if !self.mantissa.is_finite() || !other.mantissa.is_finite() || other.mantissa == 0.0 {
+            // like f64, `x % ±inf` is `x` and anything else involving infinity, NaN or a zero divisor is NaN
+            return match self.mantissa.is_finite() && other.mantissa.is_infinite() {
+                true => self,
+                false => Self::new(f64::NAN,0)
+            };
+        }
+
+        // |self| < 10^(self.exponent + 1) <= |other|
+        if self.mantissa == 0.0 || self.exponent < other.exponent {
+            return self;
+        }
+
+        let (dividend,dividend_scale) = decimal_digits(self);
+        let (divisor,divisor_scale) = decimal_digits(other);
+
+        // both are `digits * 10^scale`, so the remainder is taken on integers in units of the smaller scale
+        let (remainder,scale) = match dividend_scale >= divisor_scale {
+            true => (scaled_rem(dividend,dividend_scale.abs_diff(divisor_scale),divisor),divisor_scale),
+            // the dividend has at most 17 digits and `self.exponent >= other.exponent`, so the shift is at most 16
+            false => (dividend % (divisor * 10_u128.pow(divisor_scale.abs_diff(dividend_scale) as u32)),dividend_scale)
+        };
+
+        let remainder = match self.mantissa < 0.0 {
+            true => -(remainder as f64),
+            false => remainder as f64
+        };
+
+        Self::saturating_new(remainder,scale)
+    }
+}
+
+/// Returns the magnitude of a finite `value` as `digits * 10^scale`, taking its shortest decimal digits.
+fn decimal_digits(value : StandardForm) -> (u128,i64) {
+    let mut buffer = crate::StandardFormBuffer::new();
+    write!(buffer,"{:e}",value.mantissa.abs()).expect("`{:e}` of an f64 fits in the buffer");
+
+    let (significand,exponent) = buffer.split_once('e').expect("`{:e}` always contains an exponent");
+    let (integer,fraction) = significand.split_once('.').unwrap_or((significand,""));
+
+    let digits = integer.bytes().chain(fraction.bytes()).fold(0,|digits,digit| digits * 10 + u128::from(digit - b'0'));
+    let exponent : i64 = exponent.parse().expect("`{:e}` always has an integer exponent");
+
+    (digits,exponent + i64::from(value.exponent) - fraction.len() as i64)
+}
+
+/// Computes `(digits * 10^shift) % modulus` by modular exponentiation, so shifts beyond the range of `f64` stay exact.
+fn scaled_rem(digits : u128,shift : u64,modulus : u128) -> u128 {
+    // the modulus has at most 17 digits, so every product below fits in a u128
+    let mut power = 1 % modulus;
+    let mut base = 10 % modulus;
+    let mut remaining = shift;
+
+    while remaining > 0 {
+        if remaining & 1 == 1 {
+            power = power * base % modulus;
+        }
+
+        base = base * base % modulus;
+        remaining >>= 1;
+    }
+
+    digits % modulus * power % modulus
+}
+
+impl StandardForm {
+    /// Returns the quotient of the division rounded towards zero together with the remainder, like `(a / b).trunc()` and `a % b`.
+    #[must_use]
+    pub fn div_rem(self,other : Self) -> (Self,Self) {
+        ((self / other).truncate(),self % other)
+    }
+
+    /// Returns the quotient of the Euclidean division, such that `self == other * self.div_euclid(other) + self.rem_euclid(other)`.
+    #[must_use]
+    pub fn div_euclid(self,other : Self) -> Self {
+        let (quotient,remainder) = self.div_rem(other);
+
+        match (remainder.mantissa < 0.0,other.mantissa > 0.0) {
+            (true,true) => quotient - Self::new(1.0,0),
+            (true,false) => quotient + Self::new(1.0,0),
+            (false,_) => quotient
+        }
+    }
+
+    /// Returns the least non-negative remainder of the division, like `f64::rem_euclid`.
+    #[must_use]
+    pub fn rem_euclid(self,other : Self) -> Self {
+        let remainder = self % other;
+
+        match remainder.mantissa < 0.0 {
+            true => remainder + Self::new(other.mantissa.abs(),other.exponent),
+            false => remainder
+        }
+    }
+
+    /// Rounds towards zero.
+    fn truncate(self) -> Self {
+        // with at most 17 significant digits, larger numbers are already integers
+        match self.exponent {
+            exponent if !self.mantissa.is_finite() || exponent >= 17 => self,
+            exponent if exponent < 0 => Self::new(0.0,0),
+            exponent => Self::new((self.mantissa * pow10(exponent)) as i64 as f64,0)
+        }
     }
 }
 
 impl Add for StandardForm {
     type Output = Self;
     #[must_use]
+    /// Computed on the decimal digits of the mantissas and rounded to an `f64` once, so `0.1 + 0.2` is exactly `0.3`.
     fn add(self, other: Self) -> Self {
+        if let Some(sum) = exact_sum(self,other) {
+            return sum;
+        }
+
         let max_power = self.exponent.max(other.exponent);
-        let num_sum = self.mantissa * 10.0_f64.powf((self.exponent - max_power) as f64) + other.mantissa * 10.0_f64.powf((other.exponent - max_power) as f64);
+        let num_sum = self.mantissa * pow10(self.exponent.saturating_sub(max_power)) + other.mantissa * pow10(other.exponent.saturating_sub(max_power));
         StandardForm::new(num_sum, max_power)
     }
 }
 
+/// Returns the digits of a finite `value` with its sign, as `digits * 10^scale`.
+fn signed_digits(value : StandardForm) -> (i128,i64) {
+    let (digits,scale) = decimal_digits(value);
+    let digits = i128::try_from(digits).expect("a mantissa has at most 17 digits");
+
+    match value.mantissa < 0.0 {
+        true => (-digits,scale),
+        false => (digits,scale)
+    }
+}
+
+/// Adds the decimal digits of both numbers as integers, so only the result is rounded to an `f64`. Returns `None`
+/// if either isn't finite or their exponents are too far apart for the aligned digits to fit in an `i128`.
+fn exact_sum(a : StandardForm,b : StandardForm) -> Option<StandardForm> {
+    if !a.mantissa.is_finite() || !b.mantissa.is_finite() {
+        return None;
+    }
+
+    let ((a,a_scale),(b,b_scale)) = (signed_digits(a),signed_digits(b));
+    let scale = a_scale.min(b_scale);
+
+    let align = |digits : i128,shift : i64| 10_i128.checked_pow(u32::try_from(shift).ok()?)?.checked_mul(digits);
+    let sum = align(a,a_scale - scale)?.checked_add(align(b,b_scale - scale)?)?;
+
+    Some(StandardForm::saturating_new(sum as f64,scale))
+}
+
 impl AddAssign for StandardForm {
     fn add_assign(&mut self, other: Self) {
-        let max_power = self.exponent.max(other.exponent);
-        let num_sum = self.mantissa * 10.0_f64.powf((self.exponent - max_power) as f64) + other.mantissa * 10.0_f64.powf((other.exponent - max_power) as f64);
+        *self = *self + other;
+    }
+}
 
-        self.mantissa = num_sum;
-        self.exponent = max_power;
+/// Every power of ten that `f64` represents exactly.
+pub(crate) const POWERS_OF_TEN : [f64;23] = [
+    1e0,1e1,1e2,1e3,1e4,1e5,1e6,1e7,1e8,1e9,1e10,1e11,
+    1e12,1e13,1e14,1e15,1e16,1e17,1e18,1e19,1e20,1e21,1e22
+];
+
+/// Returns `10^exponent` without relying on `std`, exactly for every power of ten that `f64` can represent exactly.
+pub(crate) fn pow10(exponent : i32) -> f64 {
+    // beyond these, the power overflows to infinity or underflows to zero anyway
+    match exponent {
+        exponent if exponent > 330 => return f64::INFINITY,
+        exponent if exponent < -360 => return 0.0,
+        _ => {}
+    }
 
-        self.adjust();
+    let mut remaining = exponent.unsigned_abs();
+    let mut power = 1.0;
+
+    while remaining > 22 {
+        power *= 1e22;
+        remaining -= 22;
     }
-}
 
-const TOLERANCE : f64 = 1.0e6;
+    power *= POWERS_OF_TEN[remaining as usize];
 
-pub(crate) fn round(result : f64) -> f64 {
-    (result * TOLERANCE).round() / TOLERANCE
+    match exponent < 0 {
+        true => 1.0 / power,
+        false => power
+    }
 }
 
 impl Sub for StandardForm {
     type Output = Self;
     #[must_use]
+    /// Computed on the decimal digits of the mantissas and rounded to an `f64` once, so `4.6e2 - 3.4e2` is exactly `1.2e2`.
     fn sub(self, other: Self) -> Self {
-        let min = self.exponent.min(other.exponent);
-
-        let x = self.mantissa * 10_f64.powi((self.exponent - min) as i32) as f64;
-        let y = other.mantissa * 10_f64.powi((other.exponent - min) as i32) as f64;
-
-        let result = x - y;
+        if let Some(difference) = exact_sum(self,-other) {
+            return difference;
+        }
 
-        StandardForm::new(round(result),min)
+        let max_power = self.exponent.max(other.exponent);
+        let difference = self.mantissa * pow10(self.exponent.saturating_sub(max_power)) - other.mantissa * pow10(other.exponent.saturating_sub(max_power));
+        StandardForm::new(difference, max_power)
     }
 }
 
 impl SubAssign for StandardForm {
     fn sub_assign(&mut self, other: Self) {
-        let min = self.exponent.min(other.exponent);
-
-        let x = self.mantissa * 10_i32.pow((self.exponent - min) as u32) as f64;
-        let y = other.mantissa * 10_i32.pow((other.exponent - min) as u32) as f64;
-
-        let result = x - y;
-
-        self.mantissa = round(result);
-        self.exponent = min;
-        self.adjust(); 
+        *self = *self - other;
     }
 }
 
 impl Mul for StandardForm {
     type Output = Self;
     #[must_use]
+    /// Computed on the decimal digits of the mantissas and rounded to an `f64` once, so `1.2e3 * 3e2` is exactly `3.6e5`.
     fn mul(self, other: Self) -> Self {
-        let exponent = self.exponent + other.exponent;
+        // both mantissas have at most 17 digits, so the product of their digits always fits in a u128
+        if self.mantissa.is_finite() && other.mantissa.is_finite() {
+            let ((a,a_scale),(b,b_scale)) = (decimal_digits(self),decimal_digits(other));
+            let product = match (self.mantissa < 0.0) != (other.mantissa < 0.0) {
+                true => -((a * b) as f64),
+                false => (a * b) as f64
+            };
+
+            return StandardForm::saturating_new(product,a_scale + b_scale);
+        }
+
+        let exponent = i64::from(self.exponent) + i64::from(other.exponent);
         let mantissa = self.mantissa * other.mantissa;
-        StandardForm::new(round(mantissa),exponent)
+        StandardForm::saturating_new(mantissa,exponent)
     }
 }
 
 impl MulAssign for StandardForm {
     fn mul_assign(&mut self, other: Self) {
-        let exponent = self.exponent + other.exponent;
-        let mantissa = self.mantissa * other.mantissa;
-
-        self.mantissa = round(mantissa);
-        self.exponent = exponent;
-
-        self.adjust();
+        *self = *self * other;
     }
 }
 
@@ -313,21 +730,117 @@ impl Div for StandardForm {
     type Output = Self;
     #[must_use]
     fn div(self, other: Self) -> Self {
-        StandardForm::new(self.mantissa / other.mantissa,self.exponent - other.exponent)
+        StandardForm::saturating_new(self.mantissa / other.mantissa,i64::from(self.exponent) - i64::from(other.exponent))
     }
 }
 
 impl DivAssign for StandardForm {
     fn div_assign(&mut self, other: Self) {
-        self.mantissa /= other.mantissa;
-        self.exponent /= other.exponent;
-        self.adjust();
+        *self = *self / other;
     }
 }
 
 impl RemAssign for StandardForm {
     fn rem_assign(&mut self, other: StandardForm) {
-        *self = self.clone() % other;
+        *self = *self % other;
+    }
+}
+
+impl Neg for &StandardForm {
+    type Output = StandardForm;
+    fn neg(self) -> StandardForm {
+        -*self
+    }
+}
+
+/// Implements an operator for every combination of owned and borrowed operands, and its assignment with a borrowed operand.
+macro_rules! references {
+    ($($trait : ident, $fn : ident, $op : tt, $assign_trait : ident, $assign_fn : ident);*) => {
+        $(
+            impl $trait<&StandardForm> for StandardForm {
+                type Output = StandardForm;
+                fn $fn(self, other: &StandardForm) -> StandardForm {
+                    self $op *other
+                }
+            }
+
+            impl $trait<StandardForm> for &StandardForm {
+                type Output = StandardForm;
+                fn $fn(self, other: StandardForm) -> StandardForm {
+                    *self $op other
+                }
+            }
+
+            impl $trait<&StandardForm> for &StandardForm {
+                type Output = StandardForm;
+                fn $fn(self, other: &StandardForm) -> StandardForm {
+                    *self $op *other
+                }
+            }
+
+            impl $assign_trait<&StandardForm> for StandardForm {
+                fn $assign_fn(&mut self, other: &StandardForm) {
+                    *self = *self $op *other;
+                }
+            }
+        )*
+    };
+}
+
+references!(
+    Add, add, +, AddAssign, add_assign;
+    Sub, sub, -, SubAssign, sub_assign;
+    Mul, mul, *, MulAssign, mul_assign;
+    Div, div, /, DivAssign, div_assign;
+    Rem, rem, %, RemAssign, rem_assign
+);
+
+impl StandardForm {
+    /// Converts an integer given by its sign and magnitude, rounding the mantissa correctly from all of its decimal digits.
+    ///
+    /// Unlike `value as f64`, which rounds integers above 2^53 to binary first, this keeps every digit that the mantissa
+    /// can hold, e.g. `12345678901234567` stays `1.2345678901234567e16`.
+    fn from_integer(negative : bool,magnitude : u128) -> Self {
+        if magnitude == 0 {
+            return Self::new(0.0,0);
+        }
+
+        let exponent = magnitude.ilog10();
+
+        // trailing zeros only affect the exponent
+        let mut significand = magnitude;
+        let mut digits = exponent + 1;
+
+        while significand.is_multiple_of(10) {
+            significand /= 10;
+            digits -= 1;
+        }
+
+        let mantissa = match significand < 1 << 53 {
+            // an exact integer divided by an exact power of ten is rounded once
+            true => significand as f64 / POWERS_OF_TEN[digits as usize - 1],
+            false => {
+                // at most 39 digits and a decimal point, written as d.ddd for `core`'s correctly rounded parser
+                let mut buffer = [b'.';40];
+
+                for index in (0..digits as usize).rev() {
+                    buffer[index + usize::from(index > 0)] = b'0' + (significand % 10) as u8;
+                    significand /= 10;
+                }
+
+                core::str::from_utf8(&buffer[..digits as usize + 1]).ok()
+                    .and_then(|digits| digits.parse().ok())
+                    .expect("the buffer only holds ASCII digits and a decimal point")
+            }
+        };
+
+        let sign = match negative {
+            true => -1.0,
+            false => 1.0
+        };
+
+        // a rounded mantissa of 10 is normalised by `new`
+        Self::new(sign * mantissa,exponent as i32)
     }
 }
 
@@ -342,6 +855,24 @@ macro_rules! primitives {
             }
         )*
     };
+    (signed => $($t:ty),*) => {
+        $(
+            impl From<$t> for StandardForm {
+                fn from(value: $t) -> Self {
+                    StandardForm::from_integer(value < 0,value.unsigned_abs() as u128)
+                }
+            }
+        )*
+    };
+    (unsigned => $($t:ty),*) => {
+        $(
+            impl From<$t> for StandardForm {
+                fn from(value: $t) -> Self {
+                    StandardForm::from_integer(false,value as u128)
+                }
+            }
+        )*
+    };
 
     (eq => $($t:ty),*) => {
         $(
@@ -351,6 +882,12 @@ macro_rules! primitives {
                     *self == rhs
                 }
             }
+
+            impl PartialEq<StandardForm> for $t {
+                fn eq(&self,other: &StandardForm) -> bool {
+                    StandardForm::from(*self) == *other
+                }
+            }
         )*
     };
     (ord => $($t:ty),*) => {
@@ -361,6 +898,12 @@ macro_rules! primitives {
                     self.partial_cmp(&rhs)
                 }
             }
+
+            impl PartialOrd<StandardForm> for $t {
+                fn partial_cmp(&self, other: &StandardForm) -> Option<Ordering> {
+                    StandardForm::from(*self).partial_cmp(other)
+                }
+            }
         )*
     };
 
@@ -463,17 +1006,34 @@ macro_rules! primitives {
     };
     (pow => $($t : ty),*) => {
         $(
-            #[cfg(feature="num")]
+            #[cfg(all(feature="num",feature="std"))]
             impl num_traits::Pow<$t> for StandardForm {
-                type Output = f64;
+                type Output = Self;
             
                 #[must_use]
                 fn pow(self, other: $t) -> Self::Output {
-                    f64::from(self).powf(other as f64)
+                    StandardForm::pow(self,other.into())
                 }
             }
         )*
     };
+    (reversed => $($t : ty),*) => {
+        $(
+            primitives!(swapped => $t, Add, add, +);
+            primitives!(swapped => $t, Sub, sub, -);
+            primitives!(swapped => $t, Mul, mul, *);
+            primitives!(swapped => $t, Div, div, /);
+            primitives!(swapped => $t, Rem, rem, %);
+        )*
+    };
+    (swapped => $t : ty, $trait : ident, $fn : ident, $op : tt) => {
+        impl $trait<StandardForm> for $t {
+            type Output = StandardForm;
+            fn $fn(self, other: StandardForm) -> StandardForm {
+                StandardForm::from(self) $op other
+            }
+        }
+    };
     (operations => $($t:ty),*) => {
         $(
             primitives!(add => $t);
@@ -481,73 +1041,22 @@ macro_rules! primitives {
             primitives!(mul => $t);
             primitives!(div => $t);
             primitives!(rem => $t);
+            primitives!(reversed => $t);
             primitives!(pow => $t);
         )*
     }
 }
 
-primitives!(operations => i8, i16, i32, i64, u8, u16, u32, u64,f32,f64);
-primitives!(form => u8,u16,u32,u64,i8,i16,i32,i64,f32,f64);
-primitives!(eq => u8,u16,u32,u64,i8,i16,i32,i64,f32,f64);
-primitives!(ord => u8,u16,u32,u64,i8,i16,i32,i64,f32,f64);
-
-macro_rules! trig_functions {
-    ($( {
-        $(#[$attr:meta])* $fn : ident
-    })*) => {
-        #[cfg_attr(feature="js", wasm_bindgen)]
-        impl StandardForm {
-            $(
-                $(#[$attr])*
-                #[cfg(not(feature="js"))]
-                pub fn $fn <T : From<f64>>(self) -> T  {
-                    f64::from(self). $fn ().into()
-                }
-
-                $(#[$attr])*
-                #[cfg(feature="js")]
-                #[cfg_attr(feature="js", wasm_bindgen)]
-                pub fn $fn (self) -> Self  {
-                    f64::from(self). $fn ().into()
-                }
-            )*
-
-        }
-    };
-}
-
-trig_functions!(
-    { /// Computes the sine of a number (in radians).
-      sin }
-    { /// Computes the cosine of a number (in radians).
-      cos }
-    { /// Computes the tangent of a number (in radians).
-     tan }
-    { /// Computes the arcsine of a number.
-      asin }
-    { /// Computes the arccosine of a number.
-      acos }
-    { /// Computes the arctangent of a number.
-      atan }
-    { /// Computes the hyperbolic sine.
-      sinh }
-    { /// Computes the hyperbolic cosine.
-     cosh }
-    { /// Computes the hyperbolic tangent.
-      tanh }
-    { /// Computes the inverse hyperbolic sine.
-      asinh }
-    { /// Computes the inverse hyperbolic cosine.
-      acosh }
-    { /// Computes the inverse hyperbolic tangent.
-      atanh }
-);
-
+primitives!(operations => i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize,f32,f64);
+primitives!(signed => i8,i16,i32,i64,i128,isize);
+primitives!(unsigned => u8,u16,u32,u64,u128,usize);
+primitives!(form => f32,f64);
+primitives!(eq => u8,u16,u32,u64,u128,usize,i8,i16,i32,i64,i128,isize,f32,f64);
+primitives!(ord => u8,u16,u32,u64,u128,usize,i8,i16,i32,i64,i128,isize,f32,f64);
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use Ordering;
 
     #[test]
     fn assignment_issue() {
@@ -564,6 +1073,111 @@ mod tests {
         assert_eq!(r,StandardForm { mantissa : 2.0,exponent : 0 });
     }
 
+    #[test]
+    fn from_large_integers() {
+        assert_eq!(StandardForm::from(12_345_678_901_234_567_u64), StandardForm::new_unchecked(1.2345678901234567,16));
+        assert_eq!(StandardForm::from(u64::MAX), StandardForm::new_unchecked(1.8446744073709552,19));
+        assert_eq!(StandardForm::from(i64::MIN), StandardForm::new_unchecked(-9.223372036854776,18));
+        assert_eq!(StandardForm::from(u128::MAX), StandardForm::new_unchecked(3.4028236692093845,38));
+        assert_eq!(StandardForm::from(i128::MIN), StandardForm::new_unchecked(-1.7014118346046923,38));
+        assert_eq!(StandardForm::from(602_214_076_000_000_000_000_000_u128), StandardForm::new(6.02214076,23));
+        assert_eq!(StandardForm::from(99_999_999_999_999_999_999_u128), StandardForm::new(1.0,20));
+        assert_eq!(StandardForm::from(-250_isize), StandardForm::new(-2.5,2));
+        assert_eq!(StandardForm::from(0_usize), StandardForm::new(0.0,0));
+    }
+
+    #[test]
+    fn large_integer_operations() {
+        let particles = StandardForm::from(123_456_789_012_345_678_901_234_567_890_u128);
+
+        assert_eq!(particles + 10_u128.pow(29), StandardForm::new(2.234567890123457,29));
+        assert_eq!(particles / 10_usize, StandardForm::new(1.2345678901234568,28));
+        assert_eq!(particles, 123_456_789_012_345_678_901_234_567_890_u128);
+        assert!(particles > 123_456_789_012_345_600_000_000_000_000_u128);
+        assert!(StandardForm::new(-1.0,0) < 0_isize);
+        assert!(StandardForm::new(-1.0,39) < i128::MIN);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn references() {
+        fn sum_of_squares<T>(zero : T,values : &[T]) -> T where for<'a> &'a T : Add<&'a T,Output = T> + Mul<&'a T,Output = T> {
+            values.iter().fold(zero,|sum,value| &sum + &(value * value))
+        }
+
+        let a = StandardForm::new(3.0,2);
+        let b = StandardForm::new(4.0,2);
+
+        assert_eq!(sum_of_squares(StandardForm::new(0.0,0),&[a,b]), StandardForm::new(2.5,5));
+        assert_eq!(&a + &b, StandardForm::new(7.0,2));
+        assert_eq!(&a * b, StandardForm::new(1.2,5));
+        assert_eq!(a - &b, StandardForm::new(-1.0,2));
+        assert_eq!(&b / &a, b / a);
+        assert_eq!(&b % a, StandardForm::new(1.0,2));
+        assert_eq!(-&a, StandardForm::new(-3.0,2));
+
+        let mut c = a;
+        c += &b;
+        c *= &a;
+        c -= &b;
+        c /= &a;
+        c %= &b;
+        assert_eq!(c, ((((a + b) * a) - b) / a) % b);
+        assert_eq!(a, StandardForm::new(3.0,2));
+    }
+
+    #[test]
+    fn wide_exponents() {
+        let large = StandardForm::new(1.5,1000);
+
+        assert_eq!(large * StandardForm::new(2.0,-2000), StandardForm::new(3.0,-1000));
+        assert_eq!(large / StandardForm::new(5.0,-1000), StandardForm::new(3.0,1999));
+        assert_eq!(large + StandardForm::new(5.0,999), StandardForm::new(2.0,1000));
+        assert_eq!(large - StandardForm::new(1.0,0), large);
+        assert_eq!(StandardForm::new(1.0,0) - large, -large);
+        assert_eq!(f64::from(large), f64::INFINITY);
+        assert_eq!(f64::from(StandardForm::new(5.0,-324)), 5e-324);
+
+        assert_eq!(StandardForm::saturating_new(25.0,i64::from(i32::MAX) - 1), StandardForm::new(2.5,i32::MAX));
+        assert_eq!(StandardForm::saturating_new(-25.0,i64::from(i32::MAX)), StandardForm::new(f64::NEG_INFINITY,0));
+        assert_eq!(StandardForm::saturating_new(0.25,i64::from(i32::MIN)), StandardForm::new(0.0,0));
+    }
+
+    #[test]
+    fn exponent_overflow_saturates() {
+        let infinity = StandardForm::new(f64::INFINITY,0);
+        let largest = StandardForm::new(9.0,i32::MAX);
+
+        assert_eq!(StandardForm::new(1.0,i32::MAX) * StandardForm::new(1.0,1), infinity);
+        assert_eq!(StandardForm::new(5.0,i32::MAX) * StandardForm::new(2.0,0), infinity);
+        assert_eq!(StandardForm::new(-5.0,i32::MAX) * StandardForm::new(2.0,0), -infinity);
+        assert_eq!(StandardForm::new(1.0,i32::MAX) / StandardForm::new(1.0,-1), infinity);
+        assert_eq!(StandardForm::new(1.0,i32::MIN) / StandardForm::new(1.0,1), StandardForm::new(0.0,0));
+        assert_eq!(largest + largest, infinity);
+        assert_eq!(-largest - largest, -infinity);
+        assert_eq!(StandardForm::new(15.0,i32::MAX), infinity);
+        assert_eq!(StandardForm::new(0.5,i32::MIN), StandardForm::new(0.0,0));
+
+        assert_eq!(StandardForm::new(0.5,i32::MAX), StandardForm::new(5.0,i32::MAX - 1));
+        assert_eq!(StandardForm::new(1.0,i32::MIN) * StandardForm::new(1.0,1), StandardForm::new(1.0,i32::MIN + 1));
+    }
+
+    #[test]
+    fn primitives_on_the_left() {
+        let standard_form = StandardForm::new(1.5,3);
+
+        assert_eq!(2.0 * standard_form, StandardForm::new(3.0,3));
+        assert_eq!(500_u16 + standard_form, StandardForm::new(2.0,3));
+        assert_eq!(1_i8 - standard_form, StandardForm::new(-1.499,3));
+        assert_eq!(3_u64 / StandardForm::new(1.5,-3), StandardForm::new(2.0,3));
+        assert_eq!(2000_i128 % standard_form, StandardForm::new(5.0,2));
+
+        assert!(1e3 < standard_form);
+        assert!(2_u8 > StandardForm::new(1.5,0));
+        assert!(1500_usize == standard_form);
+        assert!(1500.5_f32 != standard_form);
+    }
+
     #[test]
     fn test_normalize_with_valid_range() {
         let mut sf = StandardForm::new(2.5, 3);
@@ -596,6 +1210,116 @@ mod tests {
         assert_eq!(sf.exponent, 6);
     }
 
+    #[test]
+    fn normalise_without_drift() {
+        assert_eq!(StandardForm::new(1e-120, 0), StandardForm { mantissa : 1.0, exponent : -120 });
+        assert_eq!(StandardForm::new(1230.0, 0), StandardForm { mantissa : 1.23, exponent : 3 });
+        assert_eq!(StandardForm::new(-0.000_25, 0), StandardForm { mantissa : -2.5, exponent : -4 });
+        assert_eq!(StandardForm::new(10.0, 0), StandardForm { mantissa : 1.0, exponent : 1 });
+    }
+
+    #[test]
+    fn exact_mantissa() {
+        assert_eq!(StandardForm::from(3e-4), StandardForm::new(3.0,-4));
+        assert_eq!(StandardForm::from(3e-4).to_string(), "0.0003");
+        assert_eq!(StandardForm::from(123.456), StandardForm::new(1.23456,2));
+        assert_eq!(StandardForm::from(-0.1), StandardForm::new(-1.0,-1));
+
+        for value in [3e-4, 0.1, 123.456, 7.7e-7, 1.1e21] {
+            let number = StandardForm::from(value);
+            assert_eq!(StandardForm::try_from(number.to_string().as_str()).unwrap(), number, "{value}");
+        }
+    }
+
+    #[test]
+    fn canonical_form() {
+        assert_eq!(StandardForm::new(10.0, 0), StandardForm::new(1.0, 1));
+        assert_eq!(StandardForm::new(0.0, 5), StandardForm::new(0.0, 0));
+        assert_eq!(*StandardForm::new(0.0, 5).exponent(), 0);
+        assert!(StandardForm::new(-0.0, 3).mantissa().is_sign_positive());
+        assert!((-StandardForm::new(0.0, 0)).mantissa().is_sign_positive());
+        assert_eq!(*StandardForm::new(f64::INFINITY, 7).exponent(), 0);
+
+        let nan = StandardForm::new(-f64::NAN, 4);
+        assert_eq!(nan, StandardForm::new(f64::NAN, 0));
+        assert_eq!(nan.mantissa().to_bits(), f64::NAN.to_bits());
+    }
+
+    #[test]
+    fn equal_however_constructed() {
+        for (value,text,mantissa,exponent) in [
+            (3e-4, "0.0003", 3.0, -4),
+            (0.1, "1e-1", 10.0, -2),
+            (123.456, "1.23456*10^2", 1.23456, 2),
+            (-2.5e-300, "-25e-301", -0.25, -299),
+            (1e22, "10000000000000000000000", 1.0, 22),
+            (0.0, "-0", 0.0, 7)
+        ] {
+            let from_f64 = StandardForm::from(value);
+            let from_text = StandardForm::try_from(text).unwrap();
+            let from_parts = StandardForm::new(mantissa,exponent);
+
+            assert_eq!(from_f64, from_text, "{text}");
+            assert_eq!(from_f64, from_parts, "{text}");
+        }
+    }
+
+    #[test]
+    fn total_order() {
+        let mut numbers = [
+            StandardForm::new(f64::NAN, 0),
+            StandardForm::new(1.0, 2),
+            StandardForm::new(-5.0, 3),
+            StandardForm::new(0.0, 0),
+            StandardForm::new(f64::INFINITY, 0),
+            StandardForm::new(-1.0, -5),
+            StandardForm::new(1.0, -5),
+            StandardForm::new(f64::NEG_INFINITY, 0),
+            StandardForm::new(-5.5, 3),
+            StandardForm::new(9.0, 1),
+        ];
+        numbers.sort();
+
+        assert_eq!(numbers.map(f64::from)[..9], [f64::NEG_INFINITY, -5500.0, -5000.0, -1e-5, 0.0, 1e-5, 90.0, 100.0, f64::INFINITY]);
+        assert!(numbers[9].mantissa().is_nan());
+        assert!(StandardForm::new(-5.0, 3) < StandardForm::new(1.0, 2));
+        assert!(StandardForm::new(0.0, 0) > StandardForm::new(-1.0, -5));
+    }
+
+    #[test]
+    fn decompose_is_in_range() {
+        fn check(value : f64) {
+            let (mantissa,shift) = decompose(value);
+            assert!((1.0..10.0).contains(&mantissa.abs()), "mantissa of {value:e}");
+
+            let recomposed : f64 = format!("{mantissa}e{shift}").parse().unwrap();
+            assert!((recomposed / value - 1.0).abs() < 1e-15, "{value:e} was split into {mantissa} and {shift}");
+        }
+
+        let mut state : u64 = 0x853c_49e6_748f_ea9b;
+
+        for _ in 0..100_000 {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+
+            let value = f64::from_bits(state);
+            if value.is_finite() && value != 0.0 {
+                check(value);
+            }
+        }
+
+        // the neighbours of powers of ten are where the estimated exponent is most likely to be off by one
+        let below = |value : f64| f64::from_bits(value.to_bits() - 1);
+
+        for value in [1e22, below(1e22), 1e-22, below(1e-22), below(0.1), below(10.0), f64::MAX, f64::MIN_POSITIVE] {
+            check(value);
+        }
+
+        assert_eq!(decompose(5e-324), (5.0,-324));
+        assert_eq!(decompose(-1e-120), (-1.0,-120));
+    }
+
     #[test]
     fn addition() {
         // Test addition between StandardForm instances
@@ -633,6 +1357,20 @@ mod tests {
         assert_eq!(result.exponent, 5);
     }
 
+    #[test]
+    fn full_precision() {
+        let one = StandardForm::new(1.0, 0);
+
+        // sums and products are exact on the decimal digits, and only the result is rounded to an f64
+        assert_eq!(StandardForm::new(1.0000001, 0) - one, StandardForm::new(1.0, -7));
+        assert_eq!(StandardForm::new(1.0, -1) + StandardForm::new(2.0, -1), StandardForm::new(3.0, -1));
+        assert_eq!(StandardForm::new(1.2345678901, 0) * one, StandardForm::new(1.2345678901, 0));
+        assert_eq!(StandardForm::new(1.0000001, 0) * StandardForm::new(1.0000001, 0), StandardForm::new(1.00000020000001, 0));
+        assert_eq!(StandardForm::new(-2.5, 300) * StandardForm::new(4.0, 300), StandardForm::new(-1.0, 601));
+        assert_ne!(one + StandardForm::from(f64::EPSILON), one);
+        assert_eq!(StandardForm::new(1.0, 100) + one, StandardForm::new(1.0, 100));
+    }
+
     #[test]
     fn multiplication_u8() {
         // Test multiplication with u8
@@ -653,6 +1391,64 @@ mod tests {
         assert_eq!(result.exponent, 1);
     }
 
+    #[test]
+    fn div_assign() {
+        let mut a = StandardForm::new(4.0, 2);
+        a /= StandardForm::new(2.0, 0);
+        assert_eq!(a, StandardForm::new(2.0, 2));
+
+        a /= 4u8;
+        assert_eq!(a, StandardForm::new(5.0, 1));
+    }
+
+    #[test]
+    fn remainder() {
+        let sf = |value : f64| StandardForm::from(value);
+
+        assert_eq!(sf(7.0) % sf(3.0), sf(1.0));
+        assert_eq!(sf(-7.0) % sf(3.0), sf(-1.0));
+        assert_eq!(sf(7.0) % sf(-3.0), sf(1.0));
+        assert_eq!(sf(2.0) % sf(3.0), sf(2.0));
+        assert_eq!(sf(0.7) % sf(0.3), sf(0.1));
+        assert_eq!(sf(5.5) % sf(2.5), sf(0.5));
+        assert_eq!(StandardForm::new(1.0, 100) % sf(7.0), sf(4.0));
+        assert_eq!(StandardForm::new(2.0, 120) % 3u8, sf(2.0));
+        assert_eq!(StandardForm::new(1.0, 127) % sf(9.0), sf(1.0));
+        assert_eq!(sf(9.0) % StandardForm::new(1.0, 100), sf(9.0));
+
+        // every digit of the mantissas takes part, not just the leading one
+        assert_eq!(sf(123.4) % 7u8, sf(4.4));
+        assert_eq!(StandardForm::new(1.23456789, 20) % sf(7.0), sf(1.0));
+        assert_eq!(StandardForm::new(9.87654321, 300) % sf(12.5), sf(0.0));
+        assert_eq!(StandardForm::new(-3.25719, 50) % sf(2.75193), sf(-1.8531));
+        assert_eq!(StandardForm::new(1.2345678901234567, 1016) % sf(9.8765432109), sf(5.8309256674));
+
+        assert_eq!(sf(7.0) % f64::INFINITY, sf(7.0));
+        assert!((sf(7.0) % 0u8).mantissa().is_nan());
+
+        let mut a = sf(7.0);
+        a %= 3u8;
+        assert_eq!(a, sf(7.0) % sf(3.0));
+    }
+
+    #[test]
+    fn euclidean_division() {
+        let sf = |value : f64| StandardForm::from(value);
+
+        for (a,b) in [(7.0,3.0),(-7.0,3.0),(7.0,-3.0),(-7.0,-3.0),(7.5,2.0),(-7.5,-2.0)] {
+            assert_eq!(sf(a).rem_euclid(sf(b)), sf(f64::rem_euclid(a,b)), "{a}.rem_euclid({b})");
+            assert_eq!(sf(a).div_euclid(sf(b)), sf(f64::div_euclid(a,b)), "{a}.div_euclid({b})");
+        }
+
+        // exact on the decimal values, where f64 gives 0.10000000000000004 and 1e30 isn't a power of ten
+        assert_eq!(sf(-0.5).rem_euclid(sf(0.2)), sf(0.1));
+        assert_eq!(sf(-0.5).div_euclid(sf(0.2)), sf(-3.0));
+        assert_eq!(StandardForm::new(-1.0, 30).rem_euclid(sf(7.0)), sf(6.0));
+
+        assert_eq!(sf(-7.0).div_rem(sf(3.0)), (sf(-2.0),sf(-1.0)));
+        assert_eq!(StandardForm::new(1.0, 100).div_rem(StandardForm::new(3.0, 99)), (sf(3.0),StandardForm::new(1.0, 99)));
+    }
+
     #[test]
     fn division_u8() {
         // Test division with u8
@@ -737,4 +1533,43 @@ mod tests {
         // Negative numbers are considered less than positive numbers with the same magnitude
         assert_eq!(sf1.partial_cmp(&sf2), Some(Ordering::Less));
     }
+
+    #[test]
+    fn plain_string() {
+        assert_eq!(StandardForm::new(1.234, 7).to_plain_string(), "12340000");
+        assert_eq!(StandardForm::new(1.5, 99).to_plain_string(), format!("15{}", "0".repeat(98)));
+        assert_eq!(StandardForm::new(-1.5, -3).to_plain_string(), "-0.0015");
+        assert_eq!(StandardForm::new(0.0, 0).to_plain_string(), "0");
+    }
+
+    #[test]
+    fn fixed_string() {
+        assert_eq!(StandardForm::new(1.005, 0).to_fixed_string(2), "1.01");
+        assert_eq!(StandardForm::new(9.99, 1).to_fixed_string(0), "100");
+        assert_eq!(StandardForm::new(1.5, -3).to_fixed_string(4), "0.0015");
+        assert_eq!(StandardForm::new(1.5, -3).to_fixed_string(1), "0.0");
+        assert_eq!(StandardForm::new(2.5, 0).to_fixed_string(3), "2.500");
+
+        let long = StandardForm::new(1.5, 0).to_fixed_string(1 << 20);
+        assert_eq!(long.len(), (1 << 20) + 2);
+        assert!(long.starts_with("1.50"));
+
+        assert_eq!(StandardForm::new(5.0, i32::MIN).to_fixed_string(0), "0");
+        assert_eq!(StandardForm::new(-5.0, i32::MIN).to_fixed_string(3), "0.000");
+    }
+
+    #[test]
+    fn grouped_string() {
+        assert_eq!(StandardForm::new(1.2345, 6).to_grouped_string(','), "1,234,500");
+        assert_eq!(StandardForm::new(-1.5, 2).to_grouped_string(','), "-150");
+    }
+
+    #[test]
+    fn speech_string() {
+        assert_eq!(StandardForm::new(6.02, 23).to_speech_string(), "6.02 times 10 to the power of 23");
+        assert_eq!(StandardForm::new(-1.5, -3).to_speech_string(), "minus 1.5 times 10 to the power of minus 3");
+        assert_eq!(StandardForm::new(2.0, 1).to_speech_string(), "2 times 10");
+        assert_eq!(StandardForm::new(4.5, 0).to_speech_string(), "4.5");
+        assert_eq!(StandardForm::new_unchecked(f64::NEG_INFINITY, 0).to_speech_string(), "minus infinity");
+    }
 }
\ No newline at end of file
@@ -0,0 +1,206 @@
+use core::iter::{Sum,Product};
+
+use crate::StandardForm;
+use crate::sf::pow10;
+
+impl Sum for StandardForm {
+    /// Adds the numbers one after another with `+`, starting from zero. See [`CompensatedSum`] for a more accurate sum.
+    fn sum<I : Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(StandardForm::new(0.0,0),|sum,value| sum + value)
+    }
+}
+
+impl<'a> Sum<&'a StandardForm> for StandardForm {
+    fn sum<I : Iterator<Item = &'a StandardForm>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl Product for StandardForm {
+    /// Multiplies the numbers one after another with `*`, starting from one.
+    fn product<I : Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(StandardForm::new(1.0,0),|product,value| product * value)
+    }
+}
+
+impl<'a> Product<&'a StandardForm> for StandardForm {
+    fn product<I : Iterator<Item = &'a StandardForm>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+/// Accumulates a sum with Neumaier's compensated summation, which keeps the error of the total independent of the number
+/// of terms and of how far apart their magnitudes are.
+///
+/// Adding numbers one by one loses the low digits of every term that is much smaller than the running total, so
+/// `1e20 + 1 - 1e20` is `0`. The compensated sum tracks the digits that were lost in a second accumulator and adds
+/// them back when [`CompensatedSum::total`] is called. Both accumulators are `f64` values scaled by a shared power of
+/// ten, which moves up when a term or the running sum would overflow them, so terms and totals far beyond the range of
+/// `f64` can be summed too. Terms more than about 300 orders of magnitude below the largest one are lost.
+///
+/// ```
+/// use standardform::{StandardForm,CompensatedSum};
+///
+/// let values = [StandardForm::new(1.0,20),StandardForm::new(1.0,0),StandardForm::new(-1.0,20)];
+///
+/// assert_eq!(values.iter().sum::<StandardForm>(), StandardForm::new(0.0,0));
+/// assert_eq!(values.iter().collect::<CompensatedSum>().total(), StandardForm::new(1.0,0));
+/// ```
+#[derive(Debug,Clone,Copy,Default,PartialEq)]
+pub struct CompensatedSum {
+    sum : f64,
+    compensation : f64,
+    scale : i32
+}
+
+impl CompensatedSum {
+    /// Creates an empty sum.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { sum : 0.0 , compensation : 0.0 , scale : 0 }
+    }
+
+    /// Adds a number to the sum.
+    pub fn push(&mut self,value : StandardForm) {
+        let exponent = *value.exponent();
+
+        if self.sum == 0.0 && self.compensation == 0.0 {
+            // unscaled within the range of f64, so the terms convert exactly like `f64::from` does
+            self.scale = match (-300..=300).contains(&exponent) {
+                true => 0,
+                false => exponent
+            };
+        }
+        else if exponent.saturating_sub(self.scale) > 300 || self.sum.abs() >= 1e300 {
+            // either the terms so far are more than 300 orders of magnitude below this one, so rescaling them loses
+            // nothing, or their sum is approaching `f64::MAX` and would overflow with a few more of them
+            let scale = exponent.max(self.scale.saturating_add(300));
+            let factor = pow10(self.scale.saturating_sub(scale));
+            self.sum *= factor;
+            self.compensation *= factor;
+            self.scale = scale;
+        }
+
+        let value = value.mantissa() * pow10(exponent.saturating_sub(self.scale));
+        let sum = self.sum + value;
+
+        // the low digits of the smaller operand are the ones lost by the addition
+        self.compensation += match self.sum.abs() >= value.abs() {
+            true => (self.sum - sum) + value,
+            false => (value - sum) + self.sum
+        };
+
+        self.sum = sum;
+    }
+
+    /// Returns the sum of every number added so far, which is infinite if it is too large for the exponent of a
+    /// `StandardForm`, like `+` does.
+    #[must_use]
+    pub fn total(&self) -> StandardForm {
+        match self.sum.is_finite() {
+            true => StandardForm::new(self.sum + self.compensation,self.scale),
+            // the compensation of an infinite or NaN sum is meaningless
+            false => StandardForm::new(self.sum,0)
+        }
+    }
+}
+
+impl Extend<StandardForm> for CompensatedSum {
+    fn extend<I : IntoIterator<Item = StandardForm>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<'a> Extend<&'a StandardForm> for CompensatedSum {
+    fn extend<I : IntoIterator<Item = &'a StandardForm>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl FromIterator<StandardForm> for CompensatedSum {
+    fn from_iter<I : IntoIterator<Item = StandardForm>>(iter: I) -> Self {
+        let mut sum = Self::new();
+        sum.extend(iter);
+        sum
+    }
+}
+
+impl<'a> FromIterator<&'a StandardForm> for CompensatedSum {
+    fn from_iter<I : IntoIterator<Item = &'a StandardForm>>(iter: I) -> Self {
+        iter.into_iter().copied().collect()
+    }
+}
+
+impl StandardForm {
+    /// Sums the numbers with compensated summation, see [`CompensatedSum`].
+    #[must_use]
+    pub fn compensated_sum<I : IntoIterator<Item = Self>>(values : I) -> Self {
+        values.into_iter().collect::<CompensatedSum>().total()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sums_and_products() {
+        let values = [StandardForm::new(1.5,3),StandardForm::new(2.0,2),StandardForm::new(-5.0,-1)];
+
+        assert_eq!(values.iter().sum::<StandardForm>(), StandardForm::new(1.6995,3));
+        assert_eq!(values.into_iter().sum::<StandardForm>(), StandardForm::new(1.6995,3));
+        assert_eq!(values.iter().product::<StandardForm>(), StandardForm::new(-1.5,5));
+        assert_eq!(values.into_iter().product::<StandardForm>(), StandardForm::new(-1.5,5));
+
+        assert_eq!(core::iter::empty::<StandardForm>().sum::<StandardForm>(), StandardForm::new(0.0,0));
+        assert_eq!(core::iter::empty::<StandardForm>().product::<StandardForm>(), StandardForm::new(1.0,0));
+    }
+
+    #[test]
+    fn compensated() {
+        // a tiny term between huge ones that cancel out
+        let values = [StandardForm::new(1.0,100),StandardForm::new(1.0,-3),StandardForm::new(-1.0,100)];
+        assert_eq!(StandardForm::compensated_sum(values), StandardForm::new(1.0,-3));
+
+        // a million ones, each of which is lost when added to 10^16 on its own
+        let mut sum = CompensatedSum::new();
+        sum.push(StandardForm::new(1.0,16));
+        for _ in 0..1_000_000 {
+            sum.push(StandardForm::new(1.0,0));
+        }
+        sum.extend(&[StandardForm::new(-1.0,16)]);
+
+        assert_eq!(sum.total(), StandardForm::new(1.0,6));
+
+        // terms beyond the range of f64
+        let values = [StandardForm::new(1.0,1000),StandardForm::new(1.0,-1000),StandardForm::new(1.0,800),StandardForm::new(-1.0,1000)];
+        let total = StandardForm::compensated_sum(values);
+        assert!((f64::from(total / StandardForm::new(1.0,800)) - 1.0).abs() < 1e-15);
+        assert_eq!(StandardForm::compensated_sum([StandardForm::new(2.5,-600),StandardForm::new(2.5,-600)]), StandardForm::new(5.0,-600));
+    }
+
+    #[test]
+    fn large_totals() {
+        let mut sum = CompensatedSum::new();
+        for _ in 0..1000 {
+            sum.push(StandardForm::from(9e307));
+        }
+        assert_eq!(sum.total(), StandardForm::new(9.0,310));
+
+        // each term is within the range of f64, but the total isn't
+        let mut sum = CompensatedSum::new();
+        for _ in 0..20_000_000 {
+            sum.push(StandardForm::new(9.0,300));
+        }
+        assert!((f64::from(sum.total() / StandardForm::new(1.8,308)) - 1.0).abs() < 1e-15);
+    }
+
+    #[test]
+    fn compensated_special_values() {
+        assert_eq!(StandardForm::compensated_sum([StandardForm::new(f64::INFINITY,0),StandardForm::new(1.0,0)]), StandardForm::new(f64::INFINITY,0));
+        assert!(StandardForm::compensated_sum([StandardForm::new(f64::INFINITY,0),StandardForm::new(f64::NEG_INFINITY,0)]).mantissa().is_nan());
+        assert_eq!(CompensatedSum::new().total(), StandardForm::new(0.0,0));
+    }
+}
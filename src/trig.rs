@@ -0,0 +1,430 @@
+use core::f64::consts::TAU;
+
+#[cfg(feature="js")]
+use wasm_bindgen::prelude::*;
+
+use crate::{StandardForm,DomainError};
+
+/// Represents the unit an angle is measured in.
+#[derive(Debug,Clone,Copy,PartialEq,Eq,Hash,Default)]
+#[cfg_attr(feature="js", wasm_bindgen)]
+pub enum AngleUnit {
+    /// A full turn is `2π` radians.
+    #[default]
+    Radians,
+    /// A full turn is 360 degrees.
+    Degrees,
+    /// A full turn is 400 gradians.
+    Gradians,
+    /// A full turn is 1 turn.
+    Turns
+}
+
+impl AngleUnit {
+    /// Returns the size of a full turn in this unit.
+    const fn full_turn(self) -> f64 {
+        match self {
+            Self::Radians => TAU,
+            Self::Degrees => 360.0,
+            Self::Gradians => 400.0,
+            Self::Turns => 1.0
+        }
+    }
+
+    /// Converts an angle in radians into this unit.
+    fn radians_into(self,angle : f64) -> f64 {
+        match self {
+            Self::Radians => angle,
+            Self::Degrees => angle.to_degrees(),
+            _ => angle / TAU * self.full_turn()
+        }
+    }
+
+    /// Reduces an angle in a unit with a whole number of units per turn to less than a full turn, keeping its sign.
+    ///
+    /// Below `10^15` the angle converts to `f64` exactly enough for `%`, which never rounds. Larger angles may not,
+    /// e.g. `12345678901234567` is above `2^53`, so they are reduced exactly with modular arithmetic on the shortest
+    /// digits of their mantissa, which also works beyond the range of `f64`.
+    fn reduce(self,angle : StandardForm) -> f64 {
+        if *angle.exponent() < 15 {
+            return f64::from(angle) % self.full_turn();
+        }
+
+        let scientific = format!("{:e}",angle.mantissa().abs());
+        let (significand,_) = scientific.split_once('e').expect("`{:e}` always contains an exponent");
+        let (integer,fraction) = significand.split_once('.').unwrap_or((significand,""));
+
+        // the angle is `digits * 10^scale`, with at most 17 digits, so only the last of them can be a fraction
+        let digits : u64 = format!("{integer}{fraction}").parse().expect("`{:e}` always has a decimal mantissa");
+        let scale = angle.exponent() - fraction.len() as i32;
+
+        let modulus = self.full_turn() as u64;
+
+        if scale < 0 {
+            let divisor = 10_u64.pow(scale.unsigned_abs());
+            let remainder = (digits % (modulus * divisor)) as f64 / divisor as f64;
+
+            return match *angle.mantissa() < 0.0 {
+                true => -remainder,
+                false => remainder
+            };
+        }
+
+        let mut power = 1;
+        let mut base = 10 % modulus;
+        let mut remaining = scale;
+
+        while remaining > 0 {
+            if remaining & 1 == 1 {
+                power = power * base % modulus;
+            }
+
+            base = base * base % modulus;
+            remaining >>= 1;
+        }
+
+        let remainder = (digits % modulus * power % modulus) as f64;
+
+        match *angle.mantissa() < 0.0 {
+            true => -remainder,
+            false => remainder
+        }
+    }
+
+    /// Returns the sine and cosine of an angle in this unit. Multiples of a quarter turn are exact in every unit but
+    /// radians, so `sin(180°)` is exactly `0` rather than `1.2e-16`.
+    fn sin_cos(self,angle : StandardForm) -> (f64,f64) {
+        if self == Self::Radians {
+            return f64::from(angle).sin_cos();
+        }
+
+        let angle = self.reduce(angle);
+        let quarters = angle / (self.full_turn() / 4.0);
+
+        match quarters.fract() == 0.0 {
+            true => match (quarters as i64).rem_euclid(4) {
+                0 => (0.0,1.0),
+                1 => (1.0,0.0),
+                2 => (0.0,-1.0),
+                _ => (-1.0,0.0)
+            },
+            false => (angle / self.full_turn() * TAU).sin_cos()
+        }
+    }
+}
+
+/// Checks that the argument of an inverse function lies in `[-1, 1]`, letting NaN through like `f64` does.
+fn within_unit_interval(value : StandardForm,function : &'static str) -> Result<f64,DomainError> {
+    let value = f64::from(value);
+
+    match value.abs() <= 1.0 || value.is_nan() {
+        true => Ok(value),
+        false => Err(DomainError::new(function,"[-1, 1]"))
+    }
+}
+
+/// Trigonometric functions, taking and returning angles in radians unless a unit is given.
+#[cfg_attr(feature="js", wasm_bindgen)]
+impl StandardForm {
+    /// Computes the sine of a number (in radians).
+    #[must_use]
+    pub fn sin(self) -> Self {
+        self.sin_in(AngleUnit::Radians)
+    }
+
+    /// Computes the cosine of a number (in radians).
+    #[must_use]
+    pub fn cos(self) -> Self {
+        self.cos_in(AngleUnit::Radians)
+    }
+
+    /// Computes the tangent of a number (in radians).
+    #[must_use]
+    pub fn tan(self) -> Self {
+        self.tan_in(AngleUnit::Radians)
+    }
+
+    /// Computes the sine of an angle in the given unit.
+    ///
+    /// ```
+    /// use standardform::{StandardForm,AngleUnit};
+    ///
+    /// assert_eq!(StandardForm::new(1.8,2).sin_in(AngleUnit::Degrees), StandardForm::new(0.0,0));
+    /// ```
+    #[must_use]
+    pub fn sin_in(self,unit : AngleUnit) -> Self {
+        unit.sin_cos(self).0.into()
+    }
+
+    /// Computes the cosine of an angle in the given unit.
+    #[must_use]
+    pub fn cos_in(self,unit : AngleUnit) -> Self {
+        unit.sin_cos(self).1.into()
+    }
+
+    /// Computes the tangent of an angle in the given unit, which is infinite at odd multiples of a quarter turn in
+    /// every unit but radians.
+    #[must_use]
+    pub fn tan_in(self,unit : AngleUnit) -> Self {
+        match unit {
+            AngleUnit::Radians => f64::from(self).tan().into(),
+            _ => {
+                let (sin,cos) = unit.sin_cos(self);
+                (sin / cos).into()
+            }
+        }
+    }
+
+    /// Computes the arcsine of a number (in radians).
+    ///
+    /// # Errors
+    /// Returns a [`DomainError`] if the number lies outside `[-1, 1]`.
+    pub fn asin(self) -> Result<Self,DomainError> {
+        self.asin_in(AngleUnit::Radians)
+    }
+
+    /// Computes the arccosine of a number (in radians).
+    ///
+    /// # Errors
+    /// Returns a [`DomainError`] if the number lies outside `[-1, 1]`.
+    pub fn acos(self) -> Result<Self,DomainError> {
+        self.acos_in(AngleUnit::Radians)
+    }
+
+    /// Computes the arctangent of a number (in radians).
+    #[must_use]
+    pub fn atan(self) -> Self {
+        self.atan_in(AngleUnit::Radians)
+    }
+
+    /// Computes the arcsine of a number as an angle in the given unit.
+    ///
+    /// # Errors
+    /// Returns a [`DomainError`] if the number lies outside `[-1, 1]`.
+    pub fn asin_in(self,unit : AngleUnit) -> Result<Self,DomainError> {
+        within_unit_interval(self,"asin").map(|value| unit.radians_into(value.asin()).into())
+    }
+
+    /// Computes the arccosine of a number as an angle in the given unit.
+    ///
+    /// # Errors
+    /// Returns a [`DomainError`] if the number lies outside `[-1, 1]`.
+    pub fn acos_in(self,unit : AngleUnit) -> Result<Self,DomainError> {
+        within_unit_interval(self,"acos").map(|value| unit.radians_into(value.acos()).into())
+    }
+
+    /// Computes the arctangent of a number as an angle in the given unit.
+    #[must_use]
+    pub fn atan_in(self,unit : AngleUnit) -> Self {
+        unit.radians_into(f64::from(self).atan()).into()
+    }
+
+    /// Computes the angle of the point `(other, self)` from the positive x-axis (in radians), in `[-π, π]`.
+    ///
+    /// Only the ratio of the coordinates matters, so points beyond the range of `f64` work too.
+    #[must_use]
+    pub fn atan2(self,other : Self) -> Self {
+        self.atan2_in(other,AngleUnit::Radians)
+    }
+
+    /// Computes the angle of the point `(other, self)` from the positive x-axis in the given unit, between minus and
+    /// plus half a turn.
+    #[must_use]
+    pub fn atan2_in(self,other : Self,unit : AngleUnit) -> Self {
+        // scale both coordinates by the same power of ten so they fit in an f64
+        let exponent = [self,other].into_iter()
+            .filter(|value| *value.mantissa() != 0.0 && value.mantissa().is_finite())
+            .map(|value| *value.exponent())
+            .max()
+            .unwrap_or(0);
+
+        let scale = |value : Self| match *value.mantissa() == 0.0 || !value.mantissa().is_finite() {
+            true => *value.mantissa(),
+            false => f64::from(Self::new(*value.mantissa(),value.exponent().saturating_sub(exponent)))
+        };
+
+        unit.radians_into(scale(self).atan2(scale(other))).into()
+    }
+
+    /// Computes the hyperbolic sine.
+    #[must_use]
+    pub fn sinh(self) -> Self {
+        match f64::from(self).abs() < 700.0 {
+            true => f64::from(self).sinh().into(),
+            // cosh(x) is e^|x| / 2 to within f64 precision here, and sinh(x) has the sign of x
+            false => match *self.mantissa() < 0.0 {
+                true => -self.cosh(),
+                false => self.cosh()
+            }
+        }
+    }
+
+    /// Computes the hyperbolic cosine, whose result can be far beyond the range of `f64`.
+    #[must_use]
+    pub fn cosh(self) -> Self {
+        let value = f64::from(self);
+
+        match value.abs() < 700.0 {
+            true => value.cosh().into(),
+            false => Self::from(value.abs()).exp() / Self::new(2.0,0)
+        }
+    }
+
+    /// Computes the hyperbolic tangent.
+    #[must_use]
+    pub fn tanh(self) -> Self {
+        f64::from(self).tanh().into()
+    }
+
+    /// Computes the inverse hyperbolic sine, which is defined for numbers beyond the range of `f64` too.
+    #[must_use]
+    pub fn asinh(self) -> Self {
+        let magnitude = Self::new(self.mantissa().abs(),*self.exponent());
+
+        match f64::from(magnitude) < 1.0e300 {
+            true => f64::from(self).asinh().into(),
+            // asinh(x) is ln(2x) to within f64 precision here
+            false => {
+                let result = Self::new(magnitude.mantissa() * 2.0,*magnitude.exponent()).ln();
+
+                match *self.mantissa() < 0.0 {
+                    true => -result,
+                    false => result
+                }
+            }
+        }
+    }
+
+    /// Computes the inverse hyperbolic cosine, which is NaN below 1.
+    #[must_use]
+    pub fn acosh(self) -> Self {
+        match f64::from(self) < 1.0e300 {
+            true => f64::from(self).acosh().into(),
+            false => Self::new(self.mantissa() * 2.0,*self.exponent()).ln()
+        }
+    }
+
+    /// Computes the inverse hyperbolic tangent.
+    ///
+    /// # Errors
+    /// Returns a [`DomainError`] if the number lies outside `[-1, 1]`. `atanh(±1)` is `±∞`.
+    pub fn atanh(self) -> Result<Self,DomainError> {
+        within_unit_interval(self,"atanh").map(|value| value.atanh().into())
+    }
+}
+
+impl StandardForm {
+    /// Computes the sine and cosine of a number (in radians) at once.
+    #[must_use]
+    pub fn sin_cos(self) -> (Self,Self) {
+        self.sin_cos_in(AngleUnit::Radians)
+    }
+
+    /// Computes the sine and cosine of an angle in the given unit at once.
+    #[must_use]
+    pub fn sin_cos_in(self,unit : AngleUnit) -> (Self,Self) {
+        let (sin,cos) = unit.sin_cos(self);
+        (sin.into(),cos.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use core::f64::consts::{PI,FRAC_PI_2,FRAC_PI_4};
+
+    #[test]
+    fn radians() {
+        assert_eq!(StandardForm::new(1.0,0).sin(), StandardForm::from(1.0_f64.sin()));
+        assert_eq!(StandardForm::new(1.0,0).cos(), StandardForm::from(1.0_f64.cos()));
+        assert_eq!(StandardForm::new(5.0,-1).tan(), StandardForm::from(0.5_f64.tan()));
+        assert_eq!(StandardForm::new(1.0,0).atan(), StandardForm::from(FRAC_PI_4));
+        assert_eq!(StandardForm::new(1.0,0).sin_cos(), (StandardForm::new(1.0,0).sin(),StandardForm::new(1.0,0).cos()));
+        assert_eq!(StandardForm::new(0.0,0).sin(), StandardForm::new(0.0,0));
+    }
+
+    #[test]
+    fn units() {
+        let degrees = |value : f64| StandardForm::from(value);
+
+        assert_eq!(degrees(180.0).sin_in(AngleUnit::Degrees), StandardForm::new(0.0,0));
+        assert_eq!(degrees(-90.0).sin_in(AngleUnit::Degrees), StandardForm::new(-1.0,0));
+        assert_eq!(degrees(720.0).cos_in(AngleUnit::Degrees), StandardForm::new(1.0,0));
+        assert_eq!(degrees(45.0).tan_in(AngleUnit::Degrees), StandardForm::from(FRAC_PI_4.tan()));
+        assert_eq!(degrees(30.0).sin_in(AngleUnit::Degrees), StandardForm::from(30_f64.to_radians().sin()));
+
+        assert_eq!(StandardForm::new(1.0,2).sin_in(AngleUnit::Gradians), StandardForm::new(1.0,0));
+        assert_eq!(StandardForm::new(5.0,-1).cos_in(AngleUnit::Turns), StandardForm::new(-1.0,0));
+        assert_eq!(StandardForm::new(2.5,-1).sin_cos_in(AngleUnit::Turns), (StandardForm::new(1.0,0),StandardForm::new(0.0,0)));
+
+        // 10^400 degrees is a whole number of turns plus 280 degrees
+        assert_eq!(StandardForm::new(1.0,400).sin_in(AngleUnit::Degrees), degrees(280.0).sin_in(AngleUnit::Degrees));
+        assert_eq!(StandardForm::new(-3.6,500).cos_in(AngleUnit::Degrees), StandardForm::new(1.0,0));
+        assert_eq!(StandardForm::new(2.5,20).sin_in(AngleUnit::Turns), StandardForm::new(0.0,0));
+
+        // 12345678901234567 is 127 more than a multiple of 360, but its nearest f64 is 128 more
+        assert_eq!(StandardForm::new(1.2345678901234567,16).sin_in(AngleUnit::Degrees), degrees(127.0).sin_in(AngleUnit::Degrees));
+        assert_eq!(StandardForm::new(-1.2345678901234567,16).cos_in(AngleUnit::Gradians), StandardForm::new(167.0,0).cos_in(AngleUnit::Gradians));
+        assert_eq!(StandardForm::new(1.2345678901234565,15).sin_in(AngleUnit::Degrees), StandardForm::new(336.5,0).sin_in(AngleUnit::Degrees));
+        assert_eq!(StandardForm::new(9.0,15).sin_in(AngleUnit::Degrees), StandardForm::new(0.0,0));
+    }
+
+    #[test]
+    fn inverse() {
+        assert_eq!(StandardForm::new(1.0,0).asin(), Ok(StandardForm::from(FRAC_PI_2)));
+        assert_eq!(StandardForm::new(-1.0,0).acos(), Ok(StandardForm::from(PI)));
+        assert_eq!(StandardForm::new(1.0,0).asin_in(AngleUnit::Degrees), Ok(StandardForm::new(9.0,1)));
+        assert_eq!(StandardForm::new(0.0,0).acos_in(AngleUnit::Turns), Ok(StandardForm::new(2.5,-1)));
+        assert_eq!(StandardForm::new(1.0,0).atan_in(AngleUnit::Gradians), StandardForm::new(5.0,1));
+        assert!(StandardForm::new(f64::NAN,0).asin().is_ok_and(|value| value.mantissa().is_nan()));
+    }
+
+    #[test]
+    fn domain_errors() {
+        let error = StandardForm::new(1.5,0).asin().unwrap_err();
+        assert_eq!(error.function(), "asin");
+        assert_eq!(error.domain(), "[-1, 1]");
+        assert_eq!(error.to_string(), "asin is only defined for arguments in [-1, 1]");
+
+        assert_eq!(StandardForm::new(-2.0,0).acos().unwrap_err().function(), "acos");
+        assert_eq!(StandardForm::new(1.0,100).atanh().unwrap_err().function(), "atanh");
+        assert_eq!(StandardForm::new(1.0,0).atanh(), Ok(StandardForm::new(f64::INFINITY,0)));
+        assert_eq!(StandardForm::new(5.0,-1).atanh(), Ok(StandardForm::from(0.5_f64.atanh())));
+    }
+
+    #[test]
+    fn atan2() {
+        let one = StandardForm::new(1.0,0);
+
+        assert_eq!(one.atan2(one), StandardForm::from(FRAC_PI_4));
+        assert_eq!(one.atan2(-one), StandardForm::from(3.0 * FRAC_PI_4));
+        assert_eq!(StandardForm::new(1.0,500).atan2(StandardForm::new(1.0,500)), StandardForm::from(FRAC_PI_4));
+        assert_eq!(StandardForm::new(1.0,500).atan2(StandardForm::new(-1.0,-500)), StandardForm::from(FRAC_PI_2));
+        assert_eq!(StandardForm::new(0.0,0).atan2(-one), StandardForm::from(PI));
+        assert_eq!(StandardForm::new(1.0,-500).atan2(StandardForm::new(0.0,0)), StandardForm::from(FRAC_PI_2));
+        assert_eq!(StandardForm::new(1.0,600).atan2(StandardForm::new(f64::INFINITY,0)), StandardForm::new(0.0,0));
+        assert_eq!(one.atan2_in(StandardForm::new(0.0,0),AngleUnit::Degrees), StandardForm::new(9.0,1));
+        assert_eq!((-one).atan2_in(-one,AngleUnit::Turns), StandardForm::new(-3.75,-1));
+    }
+
+    #[test]
+    fn hyperbolic() {
+        assert_eq!(StandardForm::new(1.0,0).sinh(), StandardForm::from(1.0_f64.sinh()));
+        assert_eq!(StandardForm::new(1.0,0).tanh(), StandardForm::from(1.0_f64.tanh()));
+        assert_eq!(StandardForm::new(2.0,0).acosh(), StandardForm::from(2.0_f64.acosh()));
+        assert!(StandardForm::new(5.0,-1).acosh().mantissa().is_nan());
+
+        // e^1000 / 2 is about 9.85e433
+        let large = StandardForm::new(1.0,3).cosh();
+        assert_eq!(*large.exponent(), 433);
+        assert!((large.mantissa() - 9.850_355_570_085_235).abs() < 1e-9);
+        assert_eq!(StandardForm::new(-1.0,3).sinh(), -large);
+
+        // asinh(x) is ln(2x) for large x
+        let inverse = StandardForm::new(1.0,500).asinh();
+        assert!((f64::from(inverse) - (500.0 * core::f64::consts::LN_10 + core::f64::consts::LN_2)).abs() < 1e-9);
+        assert_eq!(StandardForm::new(-1.0,500).asinh(), -inverse);
+        assert_eq!(StandardForm::new(1.0,500).acosh(), inverse);
+    }
+}
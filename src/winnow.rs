@@ -0,0 +1,174 @@
+//! Parsers for [`StandardForm`] built with [winnow](https://docs.rs/winnow).
+//!
+//! They accept the same grammar as their counterparts in [`crate::nom::complete`]: a decimal mantissa followed by
+//! `e5`, `E+05`, `*10^5`, `*10**5`, ` × 10^5` or `·10⁵`. Fractional exponents and exponents that overflow are
+//! reported as `ErrMode::Cut`.
+
+use winnow::{
+    ascii::{digit0,digit1,space0},
+    combinator::{alt,cut_err,not,opt,preceded,repeat},
+    error::{ContextError,ErrMode,StrContext,StrContextValue},
+    token::one_of,
+    ModalResult,Parser,
+};
+
+use crate::StandardForm;
+
+fn overflow(description : &'static str) -> ErrMode<ContextError> {
+    let mut error = ContextError::new();
+    error.push(StrContext::Expected(StrContextValue::Description(description)));
+    ErrMode::Cut(error)
+}
+
+/// Recognises a decimal mantissa without an exponent, such as `-1.5`, `42` or `.5`.
+pub fn mantissa<'i>(input : &mut &'i str) -> ModalResult<&'i str> {
+    (
+        opt(one_of(['+','-'])),
+        alt(((digit1,opt(('.',digit0))).void(),('.',digit1).void()))
+    )
+        .take()
+        .context(StrContext::Label("mantissa"))
+        .parse_next(input)
+}
+
+/// Parses a signed integer exponent such as `-12` or `+5`.
+pub fn integer_exponent(input : &mut &str) -> ModalResult<i32> {
+    let digits = (opt(one_of(['+','-'])),digit1).take().context(StrContext::Label("exponent")).parse_next(input)?;
+    cut_err(not(('.',digit1))).context(StrContext::Label("exponent")).parse_next(input)?;
+    digits.parse().map_err(|_| overflow("an exponent that fits in an i32"))
+}
+
+/// Parses a superscript exponent such as `⁵` or `⁻¹²`.
+pub fn superscript_exponent(input : &mut &str) -> ModalResult<i32> {
+    let sign = opt(alt(('⁻'.value(-1),'⁺'.value(1)))).parse_next(input)?.unwrap_or(1);
+
+    let magnitude : i64 = repeat(1..,alt((
+        '⁰'.value(0),'¹'.value(1),'²'.value(2),'³'.value(3),'⁴'.value(4),
+        '⁵'.value(5),'⁶'.value(6),'⁷'.value(7),'⁸'.value(8),'⁹'.value(9),
+    )))
+        .fold(|| 0_i64,|value : i64,digit : i64| value.saturating_mul(10).saturating_add(digit))
+        .context(StrContext::Label("exponent"))
+        .parse_next(input)?;
+
+    i32::try_from(sign * magnitude).map_err(|_| overflow("an exponent that fits in an i32"))
+}
+
+fn multiplier<'i>(input : &mut &'i str) -> ModalResult<&'i str> {
+    alt(("*","x","X","×","·","⋅")).parse_next(input)
+}
+
+/// Parses any exponent: `e5`, `E-3`, `*10^5`, `x10**5`, ` × 10^-3` or `·10⁵`.
+pub fn exponent(input : &mut &str) -> ModalResult<i32> {
+    alt((
+        preceded(one_of(['e','E']),integer_exponent),
+        preceded((space0,multiplier,space0,"10",alt(("^","**"))),integer_exponent),
+        preceded((space0,multiplier,space0,"10"),superscript_exponent),
+    )).parse_next(input)
+}
+
+fn build((mantissa,exponent) : (&str,i32)) -> ModalResult<StandardForm> {
+    StandardForm::from_parts(mantissa,exponent).ok_or_else(|| overflow("a normalised exponent that fits in an i32"))
+}
+
+/// Parses a string in standard form (scientific notation) and returns a `StandardForm` struct.
+///
+/// The standard form number can be written as "mantissaeexponent", "mantissaEexponent", or "mantissa*10^exponent".
+/// It supports both positive and negative exponents.
+pub fn parse_standard_form_with_required_exponent(input : &mut &str) -> ModalResult<StandardForm> {
+    (mantissa,exponent).parse_next(input).and_then(build)
+}
+
+/// Parses a string in standard form (scientific notation) and returns a `StandardForm` struct.
+///
+/// Like [`parse_standard_form_with_required_exponent`], but a plain mantissa such as `42` is accepted as well.
+pub fn parse_standard_form_with_optional_exponent(input : &mut &str) -> ModalResult<StandardForm> {
+    (mantissa,opt(exponent).map(|exponent| exponent.unwrap_or(0))).parse_next(input).and_then(build)
+}
+
+#[cfg(test)]
+mod parse_standardform_tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_standard_form_positive_exponent() {
+        let mut input = "1.23e3"; // Example input: 1230.0
+        match parse_standard_form_with_optional_exponent(&mut input) {
+            Ok(standard_form) => {
+                assert_eq!(standard_form.mantissa(), &1.23);
+                assert_eq!(standard_form.exponent(), &3);
+            },
+            Err(e) => panic!("Parsing error: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn test_parse_standard_form_negative_exponent() {
+        let mut input = "2.5*10^-2"; // Example input: 0.025
+        match parse_standard_form_with_optional_exponent(&mut input) {
+            Ok(standard_form) => {
+                assert_eq!(standard_form.mantissa(), &2.5);
+                assert_eq!(standard_form.exponent(), &-2);
+            },
+            Err(e) => panic!("Parsing error: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn test_parse_standard_form_no_exponent() {
+        let mut input = "42"; // No exponent, should be treated as 42.0 * 10^0
+        match parse_standard_form_with_optional_exponent(&mut input) {
+            Ok(standard_form) => {
+                assert_eq!(standard_form.mantissa(), &4.2);
+                assert_eq!(standard_form.exponent(), &1);
+            },
+            Err(e) => panic!("Parsing error: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn test_parse_standard_form_star_exponent() {
+        let mut input = "5*10^4"; // Example input: 50000.0
+        match parse_standard_form_with_optional_exponent(&mut input) {
+            Ok(standard_form) => {
+                assert_eq!(standard_form.mantissa(), &5.0);
+                assert_eq!(standard_form.exponent(), &4);
+            },
+            Err(e) => panic!("Parsing error: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn test_parse_standard_form_invalid_input() {
+        let mut input = "invalid"; // Invalid input, should result in an error
+        assert!(parse_standard_form_with_optional_exponent(&mut input).is_err());
+    }
+
+    #[test]
+    fn test_parse_standard_form_required_exponent() {
+        let mut input = "1.23e3";
+        assert!(parse_standard_form_with_required_exponent(&mut input).is_ok());
+        assert!(input.is_empty());
+        assert!(parse_standard_form_with_required_exponent(&mut "42").is_err());
+    }
+
+    #[test]
+    fn test_parse_standard_form_unicode() {
+        let mut input = "1.2 × 10⁻⁵";
+        assert_eq!(parse_standard_form_with_required_exponent(&mut input), Ok(StandardForm::new(1.2, -5)));
+        assert!(input.is_empty());
+    }
+
+    #[test]
+    fn test_parse_standard_form_strict_exponent() {
+        assert!(parse_standard_form_with_required_exponent(&mut "1*10^3.7").is_err());
+        assert!(matches!(parse_standard_form_with_optional_exponent(&mut "1e3.7"), Err(ErrMode::Cut(_))));
+        assert!(matches!(parse_standard_form_with_required_exponent(&mut "1e3000000000"), Err(ErrMode::Cut(_))));
+        assert!(matches!(parse_standard_form_with_required_exponent(&mut "500e2147483646"), Err(ErrMode::Cut(_))));
+        assert!(matches!(parse_standard_form_with_optional_exponent(&mut "9.99999999999999999999e2147483647"), Err(ErrMode::Cut(_))));
+        assert_eq!(parse_standard_form_with_optional_exponent(&mut "9.99999999999999999999e2147483646"), Ok(StandardForm::new(1.0, 2147483647)));
+
+        let mut input = "1e3.";
+        assert!(parse_standard_form_with_optional_exponent(&mut input).is_ok());
+        assert_eq!(input, ".");
+    }
+}
@@ -9,7 +9,10 @@ document.querySelector('button').addEventListener('click',() => {
 
     // TODO : Convert number
     try { 
-        const sf = StandardForm.new_from_string(number).into_f64();
+        const standardForm = StandardForm.new_from_string(number);
+        result.setAttribute('aria-label', standardForm.aria_label());
+
+        const sf = standardForm.into_f64();
         result.innerText = sf;
 
         let history =  document.getElementById('history');
@@ -39,6 +42,7 @@ document.querySelector('button').addEventListener('click',() => {
         history.appendChild(item)
     } catch(err) {
         result.innerText = "";
+        result.removeAttribute('aria-label');
         title.innerText = err;
         title.style.color = 'red';
         setTimeout(() => {